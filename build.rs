@@ -0,0 +1,26 @@
+use std::{env, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(read_cell_nightly)");
+
+    // `nightly` feature is a no-op on stable compiler,
+    // so that `--all-features` builds keep working there.
+    if env::var_os("CARGO_FEATURE_NIGHTLY").is_none() {
+        return;
+    }
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let is_nightly = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|output| {
+            let version = String::from_utf8_lossy(&output.stdout);
+            version.contains("-nightly") || version.contains("-dev")
+        })
+        .unwrap_or(false);
+
+    if is_nightly {
+        println!("cargo:rustc-cfg=read_cell_nightly");
+    }
+}
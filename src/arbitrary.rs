@@ -0,0 +1,42 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::ReadCell;
+
+/// Generates `ReadCell<T>` from an arbitrary `T`.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use read_cell::ReadCell;
+///
+/// let data = [3u8, 1, 2, 3, 4, 5, 6];
+///
+/// let mut u = Unstructured::new(&data);
+/// let c = ReadCell::<u16>::arbitrary(&mut u).unwrap();
+/// assert_eq!(c.get(), u16::arbitrary(&mut Unstructured::new(&data)).unwrap());
+///
+/// let c = ReadCell::<Vec<u8>>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+/// assert_eq!(c.into_inner(), Vec::<u8>::arbitrary_take_rest(Unstructured::new(&data)).unwrap());
+///
+/// assert_eq!(ReadCell::<u64>::size_hint(0), u64::size_hint(0));
+/// ```
+impl<'a, T> Arbitrary<'a> for ReadCell<T>
+where
+    T: Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(ReadCell::new)
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        T::arbitrary_take_rest(u).map(ReadCell::new)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
@@ -0,0 +1,55 @@
+use alloc::sync::Arc;
+use core::cell::Cell;
+
+use crate::ReadCell;
+
+impl<T: ?Sized> ReadCell<T> {
+    /// Converts `Arc<Cell<T>>` into `Arc<ReadCell<T>>`, reusing the allocation.
+    ///
+    /// Other `Arc<Cell<T>>` handles to the same allocation keep working,
+    /// so a writer may hold on to one and hand out read-only handles to observers.
+    ///
+    /// `Arc` only provides shared ownership here. `ReadCell<T>` is never `Sync`,
+    /// same as [`Cell<T>`], so `Arc<ReadCell<T>>` can't be sent to other threads.
+    ///
+    /// Available with `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, sync::Arc};
+    /// use read_cell::ReadCell;
+    ///
+    /// let writer = Arc::new(Cell::new(5));
+    /// let reader: Arc<ReadCell<i32>> = ReadCell::from_arc(writer.clone());
+    /// let observers = [reader.clone(), reader.clone()];
+    ///
+    /// writer.set(6);
+    ///
+    /// assert_eq!(reader.get(), 6);
+    /// assert!(observers.iter().all(|o| o.get() == 6));
+    /// assert_eq!(Arc::strong_count(&writer), 4);
+    ///
+    /// let cells: Arc<Cell<[u8]>> = Arc::new(Cell::new([1, 2, 3]));
+    /// let view: Arc<ReadCell<[u8]>> = ReadCell::from_arc(cells.clone());
+    ///
+    /// cells.as_slice_of_cells()[2].set(4);
+    /// assert_eq!(*view, [1, 2, 4]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use std::{cell::Cell, sync::Arc, thread};
+    /// use read_cell::ReadCell;
+    ///
+    /// let reader = ReadCell::from_arc(Arc::new(Cell::new(5)));
+    /// thread::spawn(move || reader.get());
+    /// ```
+    #[inline]
+    pub fn from_arc(arc: Arc<Cell<T>>) -> Arc<ReadCell<T>> {
+        let ptr = Arc::into_raw(arc) as *const ReadCell<T>;
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `Cell<T>`
+        // and is more restricted.
+        unsafe { Arc::from_raw(ptr) }
+    }
+}
@@ -0,0 +1,50 @@
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::ReadCell;
+
+/// Serializes `ReadCell<T>` into exactly the same bytes as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let value = (7u16, [1u8, 2, 3], -1i64);
+/// let c = ReadCell::new(value);
+///
+/// assert_eq!(borsh::to_vec(&c).unwrap(), borsh::to_vec(&value).unwrap());
+/// ```
+impl<T> BorshSerialize for ReadCell<T>
+where
+    T: BorshSerialize + Copy,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.get().serialize(writer)
+    }
+}
+
+/// Deserializes `ReadCell<T>` from the same bytes as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let bytes = borsh::to_vec(&0xC0FFEEu32).unwrap();
+/// let c: ReadCell<u32> = borsh::from_slice(&bytes).unwrap();
+///
+/// assert_eq!(c.get(), 0xC0FFEE);
+/// ```
+impl<T> BorshDeserialize for ReadCell<T>
+where
+    T: BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        T::deserialize_reader(reader).map(ReadCell::new)
+    }
+}
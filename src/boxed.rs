@@ -0,0 +1,90 @@
+use alloc::boxed::Box;
+use core::cell::Cell;
+
+use crate::ReadCell;
+
+impl<T: ?Sized> ReadCell<T> {
+    /// Converts `Box<Cell<T>>` into `Box<ReadCell<T>>`, reusing the allocation.
+    ///
+    /// Works for unsized values like slices too.
+    ///
+    /// Available with `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: Box<Cell<[i32]>> = Box::new(Cell::new([1, 2, 3]));
+    /// let view: Box<ReadCell<[i32]>> = ReadCell::from_box(cells);
+    ///
+    /// assert_eq!(view.len(), 3);
+    /// assert_eq!(view[1].get(), 2);
+    /// assert_eq!(*view, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_box(b: Box<Cell<T>>) -> Box<ReadCell<T>> {
+        let ptr = Box::into_raw(b) as *mut ReadCell<T>;
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `Cell<T>`.
+        unsafe { Box::from_raw(ptr) }
+    }
+
+    /// Converts `Box<ReadCell<T>>` into `Box<T>`, reusing the allocation.
+    ///
+    /// Available with `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let view: Box<ReadCell<[i32]>> = Box::<[i32]>::from([1, 2, 3]).into();
+    /// let values: Box<[i32]> = ReadCell::into_box(view);
+    ///
+    /// assert_eq!(*values, [1, 2, 3]);
+    ///
+    /// let text: Box<ReadCell<str>> = Box::<str>::from("hello").into();
+    /// assert_eq!(&*ReadCell::into_box(text), "hello");
+    /// ```
+    #[inline]
+    pub fn into_box(self: Box<ReadCell<T>>) -> Box<T> {
+        let ptr = Box::into_raw(self) as *mut T;
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and the box is owned, so no aliases exist.
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for Box<ReadCell<T>> {
+    /// Converts `Box<T>` into `Box<ReadCell<T>>`, reusing the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let view: Box<ReadCell<[u8]>> = Box::<[u8]>::from([4, 5]).into();
+    ///
+    /// assert_eq!(view.len(), 2);
+    /// assert_eq!(view[0].get(), 4);
+    /// ```
+    #[inline]
+    fn from(b: Box<T>) -> Box<ReadCell<T>> {
+        let ptr = Box::into_raw(b) as *mut ReadCell<T>;
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and the box is owned, so no `&T` aliases exist.
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+impl<T: ?Sized> From<Box<Cell<T>>> for Box<ReadCell<T>> {
+    /// Converts `Box<Cell<T>>` into `Box<ReadCell<T>>`, same as [`ReadCell::from_box`].
+    #[inline]
+    fn from(b: Box<Cell<T>>) -> Box<ReadCell<T>> {
+        ReadCell::from_box(b)
+    }
+}
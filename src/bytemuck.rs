@@ -0,0 +1,86 @@
+use core::{mem::size_of, slice};
+
+use bytemuck::{NoUninit, Zeroable};
+
+use crate::ReadCell;
+
+/// All-zero `ReadCell<T>` is valid whenever all-zero `T` is,
+/// since `ReadCell<T>` is `#[repr(transparent)]` over `T`.
+///
+/// Note that `ReadCell<T>` is never `Pod`.
+/// `Pod` requires `Copy`, which `ReadCell` can't implement, same as [`Cell`].
+/// More importantly, casting `&[ReadCell<T>]` to `&[u8]` would produce
+/// plain references to a value that may be modified through an aliasing [`Cell`].
+///
+/// [`Cell`]: `core::cell::Cell`
+///
+/// # Examples
+///
+/// ```
+/// use bytemuck::Zeroable;
+/// use read_cell::ReadCell;
+///
+/// let cells = <[ReadCell<u32>; 4]>::zeroed();
+///
+/// assert!(cells.iter().all(|c| c.get() == 0));
+/// ```
+///
+/// ```compile_fail
+/// use read_cell::ReadCell;
+///
+/// let cells = [ReadCell::new(1u32), ReadCell::new(2u32)];
+/// let bytes: &[u8] = bytemuck::cast_slice(&cells);
+/// ```
+///
+/// ```compile_fail
+/// use read_cell::ReadCell;
+///
+/// fn is_pod<T: bytemuck::Pod>() {}
+///
+/// is_pod::<ReadCell<&u32>>();
+/// ```
+unsafe impl<T: Zeroable> Zeroable for ReadCell<T> {}
+
+impl<T: NoUninit> ReadCell<T> {
+    /// Returns a view of the value as a slice of byte cells.
+    ///
+    /// Bytes are not copied, writes through an aliasing [`Cell`] are visible in the view.
+    /// `T` must be [`NoUninit`], since padding bytes are uninitialized
+    /// and reading them is undefined behavior.
+    ///
+    /// Available with `bytemuck` feature.
+    ///
+    /// [`Cell`]: `core::cell::Cell`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let register = Cell::new(u32::from_ne_bytes([1, 2, 3, 4]));
+    /// let bytes = ReadCell::from_cell(&register).as_byte_cells();
+    ///
+    /// assert_eq!(bytes.len(), 4);
+    /// assert_eq!(bytes[2].get(), 3);
+    ///
+    /// register.set(u32::from_ne_bytes([5, 6, 7, 8]));
+    /// assert_eq!(bytes.iter().map(ReadCell::get).collect::<Vec<_>>(), [5, 6, 7, 8]);
+    /// ```
+    ///
+    /// Only `NoUninit` types are accepted, and that bound is what excludes types with padding.
+    /// Types that don't implement it are rejected even without padding, e.g. tuples.
+    ///
+    /// ```compile_fail
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new((1u8, 2u8));
+    /// let bytes = c.as_byte_cells();
+    /// ```
+    #[inline]
+    pub fn as_byte_cells(&self) -> &[ReadCell<u8>] {
+        // SAFETY: `NoUninit` guarantees that all bytes of a `T` are initialized,
+        // `ReadCell<u8>` has alignment of 1 and `&ReadCell` disallows mutations.
+        unsafe { slice::from_raw_parts(self.as_ptr().cast::<ReadCell<u8>>(), size_of::<T>()) }
+    }
+}
@@ -0,0 +1,191 @@
+use core::{iter::FusedIterator, slice};
+
+use crate::ReadCell;
+
+/// An iterator over a `ReadCell<[T]>` in non-overlapping chunks, starting at the beginning.
+///
+/// The last chunk may be shorter than the rest.
+///
+/// This struct is created by [`ReadCell::chunks`].
+pub struct Chunks<'a, T> {
+    inner: slice::Chunks<'a, ReadCell<T>>,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    #[inline]
+    pub(crate) fn new(cells: &'a [ReadCell<T>], size: usize) -> Self {
+        Chunks {
+            inner: cells.chunks(size),
+        }
+    }
+}
+
+impl<T> Clone for Chunks<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Chunks {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a ReadCell<[T]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next().map(ReadCell::from_slice_of_cells)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a ReadCell<[T]>> {
+        self.inner.nth(n).map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Chunks<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next_back().map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+/// An iterator over a `ReadCell<[T]>` in non-overlapping chunks of equal size,
+/// starting at the beginning.
+///
+/// Elements that don't fit into the last chunk are available with [`remainder`].
+///
+/// This struct is created by [`ReadCell::chunks_exact`].
+///
+/// [`remainder`]: `ChunksExact::remainder`
+pub struct ChunksExact<'a, T> {
+    inner: slice::ChunksExact<'a, ReadCell<T>>,
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    #[inline]
+    pub(crate) fn new(cells: &'a [ReadCell<T>], size: usize) -> Self {
+        ChunksExact {
+            inner: cells.chunks_exact(size),
+        }
+    }
+
+    /// Returns the elements that don't fit into a chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// assert_eq!(cell_slice.chunks_exact(3).remainder(), &[7]);
+    /// ```
+    #[inline]
+    pub fn remainder(&self) -> &'a ReadCell<[T]> {
+        ReadCell::from_slice_of_cells(self.inner.remainder())
+    }
+}
+
+impl<T> Clone for ChunksExact<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ChunksExact {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = &'a ReadCell<[T]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next().map(ReadCell::from_slice_of_cells)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a ReadCell<[T]>> {
+        self.inner.nth(n).map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next_back().map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksExact<'_, T> {}
+
+impl<T> FusedIterator for ChunksExact<'_, T> {}
+
+/// An iterator over overlapping windows of a `ReadCell<[T]>`.
+///
+/// This struct is created by [`ReadCell::windows`].
+pub struct Windows<'a, T> {
+    inner: slice::Windows<'a, ReadCell<T>>,
+}
+
+impl<'a, T> Windows<'a, T> {
+    #[inline]
+    pub(crate) fn new(cells: &'a [ReadCell<T>], size: usize) -> Self {
+        Windows {
+            inner: cells.windows(size),
+        }
+    }
+}
+
+impl<T> Clone for Windows<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Windows {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a ReadCell<[T]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next().map(ReadCell::from_slice_of_cells)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a ReadCell<[T]>> {
+        self.inner.nth(n).map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a ReadCell<[T]>> {
+        self.inner.next_back().map(ReadCell::from_slice_of_cells)
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
@@ -12,10 +12,96 @@
 //! [`&T`]: `reference`
 
 #![no_std]
+#![cfg_attr(
+    read_cell_nightly,
+    feature(coerce_unsized, ptr_metadata, strict_provenance_lints)
+)]
+#![cfg_attr(
+    read_cell_nightly,
+    deny(fuzzy_provenance_casts, lossy_provenance_casts)
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod arc;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+mod iter;
+mod project;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+
+#[cfg(feature = "alloc")]
+mod rc;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "scale")]
+mod scale;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+mod sync;
+
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use self::{
+    iter::{Chunks, ChunksExact, Windows},
+    sync::{AtomicType, AtomicValue, SyncReadCell},
+};
+
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedReadCell;
+
+#[doc(hidden)]
+pub use self::project::project_field as __project_field;
 
 use core::{
     cell::{Cell, UnsafeCell},
     cmp::Ordering,
+    fmt::{
+        self, Alignment, Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp,
+        UpperHex, Write,
+    },
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    marker::PhantomData,
+    mem::{self, align_of, size_of, Discriminant, ManuallyDrop, MaybeUninit},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{
+        Add, BitAnd, BitOr, BitXor, Deref, Div, Index, Mul, Neg, Not, Range, RangeFrom, RangeFull,
+        RangeInclusive, RangeTo, RangeToInclusive, Rem, Shl, Shr, Sub,
+    },
+    panic::{RefUnwindSafe, UnwindSafe},
+    pin::Pin,
+    ptr::{self, NonNull},
+    slice, str,
 };
 
 /// A possible mutable memory location.
@@ -53,11 +139,221 @@ use core::{
 /// my_struct.special_field.set(new_value);
 /// assert_eq!(special_field_read.get(), new_value);
 /// ```
+///
+/// # Zerocopy
+///
+/// With `zerocopy` feature enabled `ReadCell<T>` implements
+/// `FromBytes`, `IntoBytes`, `KnownLayout` and `Unaligned` whenever `T` does.
+/// It never implements `Immutable`, so a `&ReadCell<T>` view is obtained
+/// from a mutable byte buffer.
+///
+/// ```
+/// # #[cfg(feature = "zerocopy")] {
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+/// use zerocopy::{FromBytes, IntoBytes, KnownLayout, Unaligned};
+///
+/// #[derive(Clone, Copy, FromBytes, IntoBytes, KnownLayout, Unaligned)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     checksum: u8,
+///     len: [u8; 2],
+/// }
+///
+/// let mut frame = [1u8, 0, 0, 1, 0xAA];
+///
+/// let (header, body) = ReadCell::<Header>::mut_from_prefix(&mut frame[..]).unwrap();
+///
+/// let writer = Cell::from_mut(header.get_mut());
+/// let reader = ReadCell::from_cell(writer);
+///
+/// assert_eq!(reader.get().kind, 1);
+/// assert_eq!(reader.get().len, [0, 1]);
+///
+/// writer.set(Header { checksum: body[0], ..writer.get() });
+/// assert_eq!(reader.get().checksum, 0xAA);
+///
+/// assert_eq!(header.as_mut_bytes(), &[1, 0xAA, 0, 1]);
+/// # }
+/// ```
 #[repr(transparent)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::KnownLayout
+    )
+)]
 pub struct ReadCell<T: ?Sized> {
     value: UnsafeCell<T>,
 }
 
+/// A value stored without alignment requirements.
+///
+/// References must always be aligned, so a `&ReadCell<u32>` can't point
+/// to a field of a `#[repr(packed)]` struct.
+/// `ReadCell<Unaligned<T>>` has alignment of 1 and can point anywhere,
+/// reading the value with [`ReadCell::get_unaligned`].
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::{ReadCell, Unaligned};
+///
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: Unaligned<u32>,
+/// }
+///
+/// let header = Header { kind: 1, len: Unaligned(0x0102_0304) };
+///
+/// assert_eq!(core::mem::size_of::<Header>(), 5);
+/// assert_eq!(ReadCell::from_ref(&header.len).get_unaligned(), 0x0102_0304);
+/// ```
+#[repr(C, packed)]
+#[derive(Clone, Copy, Default)]
+pub struct Unaligned<T>(pub T);
+
+/// A float ordered with `total_cmp`, making it [`Ord`].
+///
+/// Created by [`ReadCell::get_total_ordered`].
+/// Two values are equal only if their bit patterns are equal,
+/// so `-0.0` is less than `+0.0` and NaNs are ordered by their sign and payload.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::{ReadCell, TotalOrdered};
+///
+/// let sensors = [ReadCell::new(1.5f32), ReadCell::new(f32::NAN), ReadCell::new(-0.0)];
+///
+/// let max = sensors.iter().max_by_key(|s| s.get_total_ordered()).unwrap();
+/// assert!(max.is_nan());
+///
+/// let min = sensors.iter().map(|s| s.get_total_ordered()).min().unwrap();
+/// assert_eq!(min, TotalOrdered(-0.0));
+/// assert_ne!(min, TotalOrdered(0.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalOrdered<T>(pub T);
+
+/// `ReadCell<T>` can be sent to another thread whenever `T` can, same as [`Cell`].
+///
+/// It is never `Sync` though. While it is not possible to mutate the value
+/// through `&ReadCell<T>`, the value may be mutated through an aliasing [`&Cell<T>`]
+/// on the original thread, and reading it from another thread would be a data race.
+/// `UnsafeCell` makes `ReadCell` `!Sync` for any `T`.
+///
+/// [`&Cell<T>`]: `Cell`
+///
+/// ```compile_fail
+/// use read_cell::ReadCell;
+///
+/// fn assert_sync<T: Sync>() {}
+///
+/// assert_sync::<ReadCell<u32>>();
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new(5);
+/// let value = thread::spawn(move || c.get()).join().unwrap();
+///
+/// assert_eq!(value, 5);
+/// ```
+unsafe impl<T: ?Sized + Send> Send for ReadCell<T> {}
+
+impl<T: ?Sized + UnwindSafe> UnwindSafe for ReadCell<T> {}
+
+/// Unlike [`Cell`], `ReadCell<T>` is `RefUnwindSafe` whenever `T` is,
+/// since the value can't be modified through `&ReadCell<T>`,
+/// and so a panic can't leave it in a broken state.
+///
+/// # Examples
+///
+/// ```
+/// use std::panic::catch_unwind;
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new(5);
+///
+/// let result = catch_unwind(|| {
+///     assert_eq!(c.get(), 5);
+///     panic!("oops");
+/// });
+///
+/// assert!(result.is_err());
+/// assert_eq!(catch_unwind(|| c.get() + 1).unwrap(), 6);
+///
+/// let view: &ReadCell<[u32]> = ReadCell::from_ref(&[1, 2, 3]);
+/// assert_eq!(catch_unwind(|| view.len()).unwrap(), 3);
+/// ```
+impl<T: ?Sized + RefUnwindSafe> RefUnwindSafe for ReadCell<T> {}
+
+/// Allows `ReadCell` holding a pointer to coerce to `ReadCell` holding unsized pointer,
+/// same as [`Cell`].
+///
+/// Requires `nightly` feature and nightly compiler. On stable compiler the feature has no effect.
+/// Note that `&ReadCell<[T; N]>` coerces to `&ReadCell<[T]>` on stable anyway.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(read_cell_nightly)] {
+/// use read_cell::ReadCell;
+///
+/// let c: ReadCell<&[i32; 3]> = ReadCell::new(&[1, 2, 3]);
+/// let c: ReadCell<&[i32]> = c;
+///
+/// assert_eq!(c.get().len(), 3);
+/// # }
+/// ```
+#[cfg(read_cell_nightly)]
+impl<T: core::ops::CoerceUnsized<U>, U> core::ops::CoerceUnsized<ReadCell<U>> for ReadCell<T> {}
+
+/// Creates a new `ReadCell` with a copy of the value.
+///
+/// Same as for [`Cell`], this requires `T: Copy`.
+/// Cloning a `T: Clone` value would run arbitrary code with a reference
+/// to the inner value, which an aliasing [`Cell`] could invalidate.
+/// For values that are known not to be modified while cloned
+/// use [`get_cloned_unchecked`].
+///
+/// [`get_cloned_unchecked`]: `ReadCell::get_cloned_unchecked`
+///
+/// # Examples
+///
+/// ```
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new([1, 2, 3]);
+/// assert_eq!(c.clone().get(), [1, 2, 3]);
+///
+/// struct Fragile(Vec<u8>);
+///
+/// impl Clone for Fragile {
+///     fn clone(&self) -> Self {
+///         panic!("refusing to clone {} bytes", self.0.len())
+///     }
+/// }
+///
+/// let c = ReadCell::new(Fragile(vec![1, 2, 3]));
+///
+/// // SAFETY: Nothing else has access to `c`.
+/// let result = catch_unwind(AssertUnwindSafe(|| unsafe { ReadCell::new(c.get_cloned_unchecked()) }));
+///
+/// assert!(result.is_err());
+/// assert_eq!(c.into_inner().0, [1, 2, 3]);
+/// ```
 impl<T: Copy> Clone for ReadCell<T> {
     #[inline]
     fn clone(&self) -> ReadCell<T> {
@@ -116,172 +412,4470 @@ impl<T: Ord + Copy> Ord for ReadCell<T> {
     }
 }
 
-impl<T> From<T> for ReadCell<T> {
-    /// Creates a new `ReadCell<T>` containing the given value.
-    fn from(t: T) -> ReadCell<T> {
-        ReadCell::new(t)
+/// Hashes the copied value, producing the same hash as the value itself.
+///
+/// If the value is modified through an aliasing [`Cell`] while the cell
+/// is used as a key in a collection, the collection may misbehave.
+/// Same as with [`Cell`] or any other type with interior mutability,
+/// this is a logic error, but not undefined behavior.
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     collections::{hash_map::DefaultHasher, HashSet},
+///     hash::{Hash, Hasher},
+/// };
+/// use read_cell::ReadCell;
+///
+/// fn hash(value: impl Hash) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     value.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// assert_eq!(hash(ReadCell::new((1u16, 2u16))), hash((1u16, 2u16)));
+///
+/// let mut set = HashSet::new();
+/// set.insert(ReadCell::new(7u32));
+/// set.insert(ReadCell::new(7u32));
+/// set.insert(ReadCell::new(8u32));
+///
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(&ReadCell::new(7)));
+/// ```
+impl<T: Copy + Hash> Hash for ReadCell<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state)
     }
 }
 
-impl<T> ReadCell<T> {
-    /// Creates a new `ReadCell` containing the given value.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let c = ReadCell::new(5);
-    /// ```
+/// Compares the copied value with a plain value.
+///
+/// The reversed comparison is implemented for primitive types.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new(5);
+///
+/// assert_eq!(c, 5);
+/// assert_eq!(5, c);
+/// assert!(c != 6);
+/// assert!(c < 10 && 10 > c);
+///
+/// let nan = ReadCell::new(f64::NAN);
+/// assert!(nan != f64::NAN);
+/// assert!(f64::NAN != nan);
+/// assert_eq!(nan.partial_cmp(&0.0), None);
+/// ```
+impl<T: PartialEq + Copy> PartialEq<T> for ReadCell<T> {
     #[inline]
-    pub const fn new(value: T) -> ReadCell<T> {
-        ReadCell {
-            value: UnsafeCell::new(value),
-        }
-    }
-
-    /// Unwraps the value.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let c = ReadCell::new(5);
-    /// let five = c.into_inner();
-    ///
-    /// assert_eq!(five, 5);
-    /// ```
-    pub fn into_inner(self) -> T {
-        self.value.into_inner()
+    fn eq(&self, other: &T) -> bool {
+        self.get() == *other
     }
 }
 
-impl<T: Copy> ReadCell<T> {
-    /// Returns a copy of the contained value.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let c = ReadCell::new(5);
-    ///
-    /// let five = c.get();
-    /// ```
+/// Orders the copied value against a plain value.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let threshold = &ReadCell::new(10u32);
+///
+/// let level = match 12 {
+///     limit if *threshold >= limit => "below",
+///     _ => "above",
+/// };
+/// assert_eq!(level, "above");
+///
+/// let cells = [ReadCell::new(1), ReadCell::new(15), ReadCell::new(7)];
+/// assert_eq!(cells.iter().filter(|c| **c > 5).count(), 2);
+///
+/// let nan = ReadCell::new(f32::NAN);
+/// assert!(!(nan < 1.0) && !(nan <= 1.0) && !(nan > 1.0) && !(nan >= 1.0));
+/// assert!(!(1.0 < nan) && !(1.0 <= nan) && !(1.0 > nan) && !(1.0 >= nan));
+/// ```
+impl<T: PartialOrd + Copy> PartialOrd<T> for ReadCell<T> {
     #[inline]
-    pub fn get(&self) -> T {
-        // SAFETY: This can cause data races if called from a separate thread,
-        // but `ReadCell` is `!Sync` so this won't happen.
-        unsafe { *self.value.get() }
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.get().partial_cmp(other)
     }
-}
 
-impl<T: ?Sized> ReadCell<T> {
-    /// Returns a raw pointer to the underlying data in this cell.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let c = ReadCell::new(5);
-    ///
-    /// let ptr = c.as_ptr();
-    /// ```
     #[inline]
-    pub const fn as_ptr(&self) -> *mut T {
-        self.value.get()
+    fn lt(&self, other: &T) -> bool {
+        self.get() < *other
     }
 
-    /// Returns a mutable reference to the underlying data.
-    ///
-    /// This call borrows `ReadCell` mutably (at compile-time) which guarantees
-    /// that we possess the only reference.
-    ///
-    /// However be cautious: this method expects `self` to be mutable, which is
-    /// generally not the case when using a [`ReadCell`]. If you require interior
-    /// mutability by reference, consider using [`RefCell`] which provides
-    /// run-time checked mutable borrows through its [`borrow_mut`] method.
-    ///
-    /// [`RefCell`]: `core::cell::RefCell`
-    /// [`borrow_mut`]: `core::cell::RefCell::borrow_mut`
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let mut c = ReadCell::new(5);
-    /// *c.get_mut() += 1;
-    ///
-    /// assert_eq!(c.get(), 6);
-    /// ```
     #[inline]
-    pub fn get_mut(&mut self) -> &mut T {
-        self.value.get_mut()
+    fn le(&self, other: &T) -> bool {
+        self.get() <= *other
     }
 
-    /// Returns a `&ReadCell<T>` from a `&T`
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use read_cell::ReadCell;
-    ///
-    /// let slice: &[i32] = &[1, 2, 3];
-    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(slice);
-    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
-    ///
-    /// assert_eq!(slice_cell.len(), 3);
-    /// ```
     #[inline]
-    pub fn from_ref(t: &T) -> &ReadCell<T> {
-        // SAFETY: `&ReadCell<T>` disallows mutations.
-        unsafe { &*(t as *const T as *const ReadCell<T>) }
+    fn gt(&self, other: &T) -> bool {
+        self.get() > *other
     }
 
-    /// Returns a `&ReadCell<T>` from a `&Cell<T>`
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use std::cell::Cell;
-    /// use read_cell::ReadCell;
-    ///
-    /// let slice: &Cell<[i32]> = &Cell::new([1, 2, 3]);
-    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(slice);
-    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
-    ///
-    /// assert_eq!(slice_cell.len(), 3);
-    /// ```
     #[inline]
-    pub fn from_cell(t: &Cell<T>) -> &ReadCell<T> {
-        // SAFETY: `&ReadCell<T>` is more restricted than `Cell`.
-        unsafe { &*(t.as_ptr() as *const ReadCell<T>) }
+    fn ge(&self, other: &T) -> bool {
+        self.get() >= *other
     }
 }
 
-impl<T> ReadCell<[T]> {
-    /// Returns a `&[ReadCell<T>]` from a `&ReadCell<[T]>`
+macro_rules! impl_reversed_cmp {
+    ($($ty:ty),* $(,)?) => {$(
+        impl PartialEq<ReadCell<$ty>> for $ty {
+            #[inline]
+            fn eq(&self, other: &ReadCell<$ty>) -> bool {
+                *self == other.get()
+            }
+        }
+
+        impl PartialOrd<ReadCell<$ty>> for $ty {
+            #[inline]
+            fn partial_cmp(&self, other: &ReadCell<$ty>) -> Option<Ordering> {
+                self.partial_cmp(&other.get())
+            }
+
+            #[inline]
+            fn lt(&self, other: &ReadCell<$ty>) -> bool {
+                *self < other.get()
+            }
+
+            #[inline]
+            fn le(&self, other: &ReadCell<$ty>) -> bool {
+                *self <= other.get()
+            }
+
+            #[inline]
+            fn gt(&self, other: &ReadCell<$ty>) -> bool {
+                *self > other.get()
+            }
+
+            #[inline]
+            fn ge(&self, other: &ReadCell<$ty>) -> bool {
+                *self >= other.get()
+            }
+        }
+    )*};
+}
+
+impl_reversed_cmp!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+);
+
+/// Compares the copied value with the value copied from a [`Cell`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let writer = Cell::new(1);
+/// let view = ReadCell::from_cell(&writer);
+/// let other = Cell::new(2);
+///
+/// assert!(*view != other);
+/// assert!(*view < other);
+///
+/// writer.set(2);
+/// assert!(*view == other);
+/// assert!(*view >= other);
+/// ```
+impl<T: PartialEq + Copy> PartialEq<Cell<T>> for ReadCell<T> {
+    #[inline]
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: PartialOrd + Copy> PartialOrd<Cell<T>> for ReadCell<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        self.get().partial_cmp(&other.get())
+    }
+}
+
+/// Compares the value copied from a [`Cell`] with the copied value.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let writer = Cell::new(1);
+/// let other = Cell::new(1);
+/// let view = ReadCell::from_cell(&other);
+///
+/// assert!(writer == *view);
+///
+/// writer.set(3);
+/// assert!(writer != *view);
+/// assert!(writer > *view);
+///
+/// other.set(3);
+/// assert!(writer == *view);
+/// ```
+impl<T: PartialEq + Copy> PartialEq<ReadCell<T>> for Cell<T> {
+    #[inline]
+    fn eq(&self, other: &ReadCell<T>) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: PartialOrd + Copy> PartialOrd<ReadCell<T>> for Cell<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &ReadCell<T>) -> Option<Ordering> {
+        self.get().partial_cmp(&other.get())
+    }
+}
+
+/// Compares slices element-wise, copying elements one by one.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cell = Cell::new([1, 2, 3]);
+/// let view: &ReadCell<[i32]> = ReadCell::from_cell(&cell);
+/// let expected: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 5, 3]);
+/// let prefix: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 5]);
+///
+/// assert!(view != expected);
+/// assert!(prefix != expected);
+///
+/// cell.set([1, 5, 3]);
+/// assert!(view == expected);
+/// ```
+impl<T: PartialEq + Copy> PartialEq for ReadCell<[T]> {
+    #[inline]
+    fn eq(&self, other: &ReadCell<[T]>) -> bool {
+        let lhs = self.as_slice_of_cells();
+        let rhs = other.as_slice_of_cells();
+
+        lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(l, r)| l.get() == r.get())
+    }
+}
+
+impl<T: Eq + Copy> Eq for ReadCell<[T]> {}
+
+/// Compares the slice with a plain slice element-wise,
+/// copying elements one by one.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cell = Cell::new([1, 2, 3]);
+/// let view: &ReadCell<[u32]> = ReadCell::from_cell(&cell);
+///
+/// assert_eq!(view, &[1, 2, 3][..]);
+/// assert_eq!(*view, [1, 2, 3]);
+/// assert_eq!([1, 2, 3], *view);
+/// assert!(view != &[1, 2][..]);
+///
+/// cell.set([3, 2, 1]);
+/// assert_eq!(*view, &[3, 2, 1][..]);
+///
+/// let empty: &ReadCell<[u32]> = ReadCell::from_ref(&[]);
+/// assert_eq!(empty, &[][..]);
+/// assert!(empty != &[1][..]);
+/// ```
+impl<T: PartialEq + Copy> PartialEq<[T]> for ReadCell<[T]> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        let cells = self.as_slice_of_cells();
+
+        cells.len() == other.len() && cells.iter().zip(other).all(|(c, v)| c.get() == *v)
+    }
+}
+
+impl<'a, T: PartialEq + Copy> PartialEq<&'a [T]> for ReadCell<[T]> {
+    #[inline]
+    fn eq(&self, other: &&'a [T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq + Copy, const N: usize> PartialEq<[T; N]> for ReadCell<[T]> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq + Copy> PartialEq<ReadCell<[T]>> for [T] {
+    #[inline]
+    fn eq(&self, other: &ReadCell<[T]>) -> bool {
+        *other == *self
+    }
+}
+
+impl<T: PartialEq + Copy, const N: usize> PartialEq<ReadCell<[T]>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &ReadCell<[T]>) -> bool {
+        *other == self[..]
+    }
+}
+
+/// Compares slices [lexicographically](Ord#lexicographical-comparison),
+/// copying elements one by one.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let short: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2]);
+/// let long: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2, 0]);
+/// let big: &ReadCell<[u8]> = ReadCell::from_ref(&[3]);
+///
+/// assert!(short < long);
+/// assert!(long < big);
+/// assert_eq!(short.cmp(long), [1, 2][..].cmp(&[1, 2, 0][..]));
+///
+/// let nan: &ReadCell<[f32]> = ReadCell::from_ref(&[1.0, f32::NAN]);
+/// assert_eq!(nan.partial_cmp(nan), None);
+/// ```
+impl<T: PartialOrd + Copy> PartialOrd for ReadCell<[T]> {
+    fn partial_cmp(&self, other: &ReadCell<[T]>) -> Option<Ordering> {
+        let lhs = self.as_slice_of_cells();
+        let rhs = other.as_slice_of_cells();
+
+        for (l, r) in lhs.iter().zip(rhs) {
+            match l.get().partial_cmp(&r.get()) {
+                Some(Ordering::Equal) => {}
+                non_eq => return non_eq,
+            }
+        }
+
+        lhs.len().partial_cmp(&rhs.len())
+    }
+}
+
+impl<T: Ord + Copy> Ord for ReadCell<[T]> {
+    fn cmp(&self, other: &ReadCell<[T]>) -> Ordering {
+        let lhs = self.as_slice_of_cells();
+        let rhs = other.as_slice_of_cells();
+
+        for (l, r) in lhs.iter().zip(rhs) {
+            match l.get().cmp(&r.get()) {
+                Ordering::Equal => {}
+                non_eq => return non_eq,
+            }
+        }
+
+        lhs.len().cmp(&rhs.len())
+    }
+}
+
+/// Hashes the length and then each element, copying elements one by one.
+///
+/// The resulting hash is consistent with `Eq`,
+/// but is not guaranteed to match the hash of the plain slice.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use read_cell::ReadCell;
+///
+/// let a: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2, 3]);
+/// let b: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2, 3]);
+///
+/// let mut set = HashSet::new();
+/// set.insert(a);
+/// assert!(set.contains(b));
+/// ```
+impl<T: Hash + Copy> Hash for ReadCell<[T]> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let cells = self.as_slice_of_cells();
+
+        state.write_usize(cells.len());
+        for cell in cells {
+            cell.get().hash(state);
+        }
+    }
+}
+
+/// Compares strings byte-wise.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let a = ReadCell::from_ref("läuft");
+/// let b = ReadCell::from_ref("läuft");
+///
+/// assert!(a == b);
+/// assert!(a != ReadCell::from_ref("lauft"));
+/// ```
+impl PartialEq for ReadCell<str> {
+    #[inline]
+    fn eq(&self, other: &ReadCell<str>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for ReadCell<str> {}
+
+/// Compares the string byte-wise with a plain string.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let message = String::from("grüße, 世界");
+/// let view = ReadCell::from_ref(&message[..]);
+///
+/// assert!(view == "grüße, 世界");
+/// assert!(*view == "grüße, 世界");
+/// assert!(*"grüße, 世界" == *view);
+/// assert!(view != "grüsse, 世界");
+/// assert!(view != "grüße");
+/// ```
+impl PartialEq<str> for ReadCell<str> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        *self.as_bytes() == *other.as_bytes()
+    }
+}
+
+impl<'a> PartialEq<&'a str> for ReadCell<str> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<ReadCell<str>> for str {
+    #[inline]
+    fn eq(&self, other: &ReadCell<str>) -> bool {
+        *other == *self
+    }
+}
+
+/// Compares strings byte-wise, same as `str` does.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// assert!(ReadCell::from_ref("abc") < ReadCell::from_ref("abcd"));
+/// assert!(ReadCell::from_ref("abcd") < ReadCell::from_ref("b"));
+/// assert!(ReadCell::from_ref("z") < ReadCell::from_ref("ä"));
+/// ```
+impl PartialOrd for ReadCell<str> {
+    #[inline]
+    fn partial_cmp(&self, other: &ReadCell<str>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReadCell<str> {
+    #[inline]
+    fn cmp(&self, other: &ReadCell<str>) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+/// Hashes the string bytes, feeding them to the hasher in chunks.
+///
+/// The resulting hash is consistent with `Eq`,
+/// but is not guaranteed to match the hash of the plain `str`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use read_cell::ReadCell;
+///
+/// let mut map = HashMap::new();
+/// map.insert(ReadCell::from_ref("key"), 1);
+///
+/// assert_eq!(map.get(ReadCell::from_ref("key")), Some(&1));
+/// ```
+impl Hash for ReadCell<str> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut buf = [0u8; 64];
+        for chunk in self.as_bytes().as_slice_of_cells().chunks(buf.len()) {
+            for (dst, src) in buf.iter_mut().zip(chunk) {
+                *dst = src.get();
+            }
+            state.write(&buf[..chunk.len()]);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+/// Formats the copied value the same way [`Cell`] does.
+///
+/// Formatting flags like alternate mode, width and precision are passed
+/// through to the inner value.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// #[derive(Debug)]
+/// struct Sensor {
+///     id: u8,
+///     reading: ReadCell<f32>,
+///     raw: ReadCell<[u8; 2]>,
+/// }
+///
+/// let sensor = Sensor {
+///     id: 1,
+///     reading: ReadCell::new(0.125),
+///     raw: ReadCell::new([3, 4]),
+/// };
+///
+/// assert_eq!(format!("{:?}", ReadCell::new(5)), "ReadCell { value: 5 }");
+/// assert_eq!(format!("{:?}", ReadCell::new(-1i32)), format!("{:?}", Cell::new(-1i32)).replace("Cell", "ReadCell"));
+/// assert_eq!(format!("{:.1?}", sensor.reading), "ReadCell { value: 0.1 }");
+/// assert_eq!(
+///     format!("{:?}", sensor),
+///     "Sensor { id: 1, reading: ReadCell { value: 0.125 }, raw: ReadCell { value: [3, 4] } }",
+/// );
+/// assert_eq!(
+///     format!("{:#?}", sensor.raw),
+///     "ReadCell {\n    value: [\n        3,\n        4,\n    ],\n}",
+/// );
+/// ```
+impl<T: Copy + Debug> Debug for ReadCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadCell")
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+/// Formats elements of the slice, copying them one by one.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cell = Cell::new([1u8, 2, 3, 4]);
+/// let view: &ReadCell<[u8]> = ReadCell::from_cell(&cell);
+///
+/// assert_eq!(format!("{:?}", view), "ReadCell { value: [1, 2, 3, 4] }");
+///
+/// cell.set([5, 6, 7, 8]);
+/// assert_eq!(format!("{:?}", view), "ReadCell { value: [5, 6, 7, 8] }");
+///
+/// let pair: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2]);
+/// assert_eq!(format!("{:#?}", pair), "ReadCell {\n    value: [\n        1,\n        2,\n    ],\n}");
+///
+/// let empty: &ReadCell<[u8]> = ReadCell::from_ref(&[]);
+/// assert_eq!(format!("{:?}", empty), "ReadCell { value: [] }");
+/// ```
+impl<T: Copy + Debug> Debug for ReadCell<[T]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Elements<'a, T>(&'a [ReadCell<T>]);
+
+        impl<T: Copy + Debug> Debug for Elements<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list()
+                    .entries(self.0.iter().map(ReadCell::get))
+                    .finish()
+            }
+        }
+
+        f.debug_struct("ReadCell")
+            .field("value", &Elements(self.as_slice_of_cells()))
+            .finish()
+    }
+}
+
+/// Formats the string as quoted and escaped text.
+///
+/// Bytes are copied out in chunks, so no `&str` to the inner value is ever created.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let text = ReadCell::from_ref("say \"привет\"\n");
+///
+/// assert_eq!(
+///     format!("{:?}", text),
+///     r#"ReadCell { value: "say \"привет\"\n" }"#
+/// );
+///
+/// // Multi-byte characters straddle internal chunk boundaries.
+/// let long = "ж".repeat(100);
+/// assert_eq!(
+///     format!("{:?}", ReadCell::from_ref(&long[..])),
+///     format!("ReadCell {{ value: {:?} }}", long),
+/// );
+///
+/// // Output matches `str`, including quotes and combining marks
+/// // anywhere in the string, even at the start of an internal chunk.
+/// let samples = [
+///     String::from("it's e\u{301} ok"),
+///     String::from("\u{301}'\"\\\t\u{7f}\u{200b}"),
+///     format!("{}e\u{301}'{}", "a".repeat(63), "\u{301}".repeat(40)),
+/// ];
+///
+/// for s in &samples {
+///     assert_eq!(
+///         format!("{:?}", ReadCell::from_ref(&s[..])),
+///         format!("ReadCell {{ value: {:?} }}", s),
+///     );
+/// }
+/// assert_eq!(
+///     format!("{:?}", ReadCell::from_ref("it's e\u{301} ok")),
+///     r#"ReadCell { value: "it's e\u{301} ok" }"#,
+/// );
+/// ```
+impl Debug for ReadCell<str> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Text<'a>(&'a ReadCell<str>);
+
+        impl Debug for Text<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_char('"')?;
+                self.0.for_each_chunk(|chunk| {
+                    // Same rules as `<str as Debug>`, applied to each char independently,
+                    // so output doesn't depend on where chunks are split.
+                    // `str::escape_debug` differs, it escapes `'`
+                    // and grapheme extenders only at the start of the string.
+                    for c in chunk.chars() {
+                        match c {
+                            '\'' => f.write_char(c)?,
+                            _ => write!(f, "{}", c.escape_debug())?,
+                        }
+                    }
+                    Ok(())
+                })?;
+                f.write_char('"')
+            }
+        }
+
+        f.debug_struct("ReadCell")
+            .field("value", &Text(self))
+            .finish()
+    }
+}
+
+/// Formats the copied value exactly as the value itself.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new(3.14159);
+///
+/// assert_eq!(format!("{:>8.2}", c), format!("{:>8.2}", 3.14159));
+/// assert_eq!(format!("{:+}", ReadCell::new(5)), "+5");
+/// assert_eq!(format!("{:>8}", ReadCell::new(42u32)), "      42");
+/// assert_eq!(format!("{:*^9.3}", ReadCell::new(0.5f64)), "**0.500**");
+/// ```
+impl<T: Copy + Display> Display for ReadCell<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+/// Writes the string to the formatter.
+///
+/// Bytes are copied out in chunks, so no `&str` to the inner value is ever created.
+/// Width, fill, alignment and precision are honored the same way as for `str`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let text = ReadCell::from_ref("日本語 🦀");
+///
+/// assert_eq!(format!("{}", text), "日本語 🦀");
+/// assert_eq!(format!("[{:^10}]", text), "[  日本語 🦀   ]");
+/// assert_eq!(format!("{:*>8.3}", text), format!("{:*>8.3}", "日本語 🦀"));
+///
+/// // Multi-byte characters straddle internal chunk boundaries.
+/// let long = "🦀ж".repeat(50);
+/// assert_eq!(format!("{}", ReadCell::from_ref(&long[..])), long);
+///
+/// let mut message = String::from("done ✓");
+/// let bytes = Cell::from_mut(unsafe { message.as_bytes_mut() }).as_slice_of_cells();
+/// let view = unsafe { &*(bytes as *const [Cell<u8>] as *const ReadCell<str>) };
+///
+/// assert_eq!(format!("{}", view), "done ✓");
+/// bytes[0].set(b'D');
+/// assert_eq!(format!("{}", view), "Done ✓");
+/// ```
+impl Display for ReadCell<str> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.for_each_chunk(|chunk| f.write_str(chunk));
+        }
+
+        let mut count = 0;
+        self.for_each_chunk(|chunk| {
+            count += chunk.chars().count();
+            Ok(())
+        })?;
+
+        let count = count.min(f.precision().unwrap_or(usize::MAX));
+        let padding = f.width().map_or(0, |width| width.saturating_sub(count));
+        let (pre, post) = match f.align() {
+            None | Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        };
+
+        let fill = f.fill();
+        for _ in 0..pre {
+            f.write_char(fill)?;
+        }
+
+        let mut left = count;
+        self.for_each_chunk(|chunk| {
+            let end = chunk
+                .char_indices()
+                .nth(left)
+                .map_or(chunk.len(), |(idx, _)| idx);
+            left -= chunk[..end].chars().count();
+            f.write_str(&chunk[..end])
+        })?;
+
+        for _ in 0..post {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($trait:ident => $spec:literal, $value:literal;)*) => {$(
+        #[doc = concat!("Formats the copied value with [`", stringify!($trait), "`] formatting.")]
+        ///
+        /// All formatting flags are passed through to the inner value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("assert_eq!(format!(\"", $spec, "\", ReadCell::new(", stringify!($value), ")), format!(\"", $spec, "\", ", stringify!($value), "));")]
+        /// ```
+        impl<T: Copy + $trait> $trait for ReadCell<T> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                $trait::fmt(&self.get(), f)
+            }
+        }
+    )*};
+}
+
+impl_fmt! {
+    Binary => "{:#010b}", 0x5Au8;
+    Octal => "{:#o}", 0x5Au8;
+    LowerHex => "{:#010x}", 0xDEADu32;
+    UpperHex => "{:>4X}", 0x5Au8;
+    LowerExp => "{:+.3e}", 1234.5f64;
+    UpperExp => "{:>12E}", 0.00125f32;
+}
+
+/// Formats the address of the inner value.
+///
+/// Wide pointers are formatted the same way as `&T` is.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let c = Cell::new(5);
+/// let view = ReadCell::from_cell(&c);
+///
+/// assert_eq!(format!("{:p}", *view), format!("{:p}", &c));
+/// assert_eq!(format!("{:p}", *view), format!("{:p}", c.as_ptr()));
+///
+/// let data: &[i32] = &[1, 2, 3];
+/// let slice_view: &ReadCell<[i32]> = ReadCell::from_ref(data);
+///
+/// assert_eq!(format!("{:p}", slice_view), format!("{:p}", slice_view.as_ptr()));
+/// assert_eq!(format!("{:p}", slice_view), format!("{:p}", data));
+/// ```
+impl<T: ?Sized> Pointer for ReadCell<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+macro_rules! impl_ops {
+    ($($trait:ident::$method:ident => $op:tt, $lhs:literal, $rhs:literal $(, $bad_lhs:literal, $bad_rhs:literal)?;)*) => {$(
+        #[doc = concat!("Applies `", stringify!($op), "` to the copied values.")]
+        ///
+        /// Forwards to the operator of `T`, so overflow and panics behave exactly the same.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let a = &ReadCell::new(", stringify!($lhs), ");")]
+        #[doc = concat!("let b = &ReadCell::new(", stringify!($rhs), ");")]
+        ///
+        #[doc = concat!("assert_eq!(a ", stringify!($op), " b, ", stringify!($lhs), " ", stringify!($op), " ", stringify!($rhs), ");")]
+        #[doc = concat!("assert_eq!(a ", stringify!($op), " ", stringify!($rhs), ", ", stringify!($lhs), " ", stringify!($op), " ", stringify!($rhs), ");")]
+        #[doc = concat!("assert_eq!(", stringify!($lhs), " ", stringify!($op), " b, ", stringify!($lhs), " ", stringify!($op), " ", stringify!($rhs), ");")]
+        /// ```
+        $(
+        ///
+        /// Panics in the same cases as `T`'s operator.
+        ///
+        /// ```should_panic
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let a = &ReadCell::new(", stringify!($bad_lhs), ");")]
+        #[doc = concat!("let b = &ReadCell::new(", stringify!($bad_rhs), ");")]
+        ///
+        #[doc = concat!("let _ = std::hint::black_box(a) ", stringify!($op), " std::hint::black_box(b);")]
+        /// ```
+        )?
+        impl<T: Copy + $trait> $trait<&ReadCell<T>> for &ReadCell<T> {
+            type Output = T::Output;
+
+            #[inline]
+            fn $method(self, rhs: &ReadCell<T>) -> T::Output {
+                $trait::$method(self.get(), rhs.get())
+            }
+        }
+
+        impl<T: Copy + $trait> $trait<T> for &ReadCell<T> {
+            type Output = T::Output;
+
+            #[inline]
+            fn $method(self, rhs: T) -> T::Output {
+                $trait::$method(self.get(), rhs)
+            }
+        }
+    )*};
+}
+
+impl_ops! {
+    Add::add => +, 7, 2, 255u8, 1u8;
+    Sub::sub => -, 7.5, 2.0, 0u32, 1u32;
+    Mul::mul => *, 7u8, 2u8, 128u8, 2u8;
+    Div::div => /, 7.0f32, 2.0f32, 1i32, 0i32;
+    Rem::rem => %, -7i64, 2i64, 1u16, 0u16;
+    BitAnd::bitand => &, 0b1100u8, 0b1010u8;
+    BitOr::bitor => |, 0b1100u64, 0b1010u64;
+    BitXor::bitxor => ^, true, false;
+}
+
+macro_rules! impl_reversed_ops {
+    ($traits:tt => $($ty:ty),* $(,)?) => {$(
+        impl_reversed_ops!(@impl $ty, $traits);
+    )*};
+    (@impl $ty:ty, [$($trait:ident::$method:ident),*]) => {$(
+        impl $trait<&ReadCell<$ty>> for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn $method(self, rhs: &ReadCell<$ty>) -> $ty {
+                $trait::$method(self, rhs.get())
+            }
+        }
+    )*};
+}
+
+impl_reversed_ops!(
+    [Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem] =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+);
+
+impl_reversed_ops!(
+    [BitAnd::bitand, BitOr::bitor, BitXor::bitxor] =>
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool,
+);
+
+macro_rules! impl_shift_ops {
+    ($($trait:ident::$method:ident => $op:tt),* $(,)?) => {$(
+        #[doc = concat!("Shifts the copied value with `", stringify!($op), "`.")]
+        ///
+        /// The shift amount may be a plain integer or another `&ReadCell`.
+        /// Forwards to the operator of `T`, so overflow and panics behave exactly the same.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use read_cell::ReadCell;
+        ///
+        /// let a = &ReadCell::new(0x0Fu8);
+        /// let b = &ReadCell::new(0xF0u64);
+        ///
+        #[doc = concat!("assert_eq!(a ", stringify!($op), " 2, 0x0Fu8 ", stringify!($op), " 2);")]
+        #[doc = concat!("assert_eq!(a ", stringify!($op), " 2u32, 0x0Fu8 ", stringify!($op), " 2u32);")]
+        #[doc = concat!("assert_eq!(a ", stringify!($op), " &ReadCell::new(3i64), 0x0Fu8 ", stringify!($op), " 3i64);")]
+        #[doc = concat!("assert_eq!(b ", stringify!($op), " &ReadCell::new(4u8), 0xF0u64 ", stringify!($op), " 4u8);")]
+        /// ```
+        ///
+        /// ```should_panic
+        /// use read_cell::ReadCell;
+        ///
+        /// let a = &ReadCell::new(1u8);
+        #[doc = concat!("let _ = std::hint::black_box(a) ", stringify!($op), " std::hint::black_box(8);")]
+        /// ```
+        impl<T: Copy + $trait<U>, U: Copy> $trait<&ReadCell<U>> for &ReadCell<T> {
+            type Output = T::Output;
+
+            #[inline]
+            fn $method(self, rhs: &ReadCell<U>) -> T::Output {
+                $trait::$method(self.get(), rhs.get())
+            }
+        }
+
+        impl_shift_ops!(@amount $trait::$method => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    )*};
+    (@amount $trait:ident::$method:ident => $($ty:ty),*) => {$(
+        impl<T: Copy + $trait<$ty>> $trait<$ty> for &ReadCell<T> {
+            type Output = T::Output;
+
+            #[inline]
+            fn $method(self, rhs: $ty) -> T::Output {
+                $trait::$method(self.get(), rhs)
+            }
+        }
+    )*};
+}
+
+impl_shift_ops! {
+    Shl::shl => <<,
+    Shr::shr => >>,
+}
+
+/// Applies unary `-` to the copied value.
+///
+/// Forwards to the operator of `T`, so overflow and panics behave exactly the same.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// assert_eq!(-&ReadCell::new(5i64), -5);
+/// assert_eq!(-&ReadCell::new(1.5f32), -1.5);
+/// ```
+///
+/// ```should_panic
+/// use read_cell::ReadCell;
+///
+/// let a = &ReadCell::new(i8::MIN);
+/// let _ = -std::hint::black_box(a);
+/// ```
+impl<T: Copy + Neg> Neg for &ReadCell<T> {
+    type Output = T::Output;
+
+    #[inline]
+    fn neg(self) -> T::Output {
+        Neg::neg(self.get())
+    }
+}
+
+/// Applies unary `!` to the copied value.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// assert_eq!(!&ReadCell::new(0b1010_1010u8), 0b0101_0101);
+/// assert_eq!(!&ReadCell::new(0u64), u64::MAX);
+/// assert_eq!(!&ReadCell::new(true), false);
+/// ```
+impl<T: Copy + Not> Not for &ReadCell<T> {
+    type Output = T::Output;
+
+    #[inline]
+    fn not(self) -> T::Output {
+        Not::not(self.get())
+    }
+}
+
+macro_rules! impl_sum_product {
+    ($($ty:ident),* $(,)?) => {$(
+        /// Sums the values copied from cells, same as for `&T`.
+        ///
+        /// Each cell is read when the iterator yields it, so writes made through
+        /// an aliasing [`Cell`] during summation are observed by cells not yet read.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::cell::Cell;
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let cells: &Cell<[", stringify!($ty), "]> = &Cell::new([1, 2, 3, 4].map(|x: u8| x as ", stringify!($ty), "));")]
+        #[doc = concat!("let view: &ReadCell<[", stringify!($ty), "]> = ReadCell::from_cell(cells);")]
+        ///
+        #[doc = concat!("assert_eq!(view.iter().sum::<", stringify!($ty), ">(), 10 as ", stringify!($ty), ");")]
+        ///
+        /// let writer = cells.as_slice_of_cells();
+        #[doc = concat!("let total: ", stringify!($ty), " = view")]
+        ///     .iter()
+        ///     .enumerate()
+        ///     .inspect(|&(i, _)| if i == 1 { writer[3].set(writer[3].get() + writer[3].get()) })
+        ///     .map(|(_, cell)| cell)
+        ///     .sum();
+        ///
+        #[doc = concat!("assert_eq!(total, 14 as ", stringify!($ty), ");")]
+        /// ```
+        impl<'a> Sum<&'a ReadCell<$ty>> for $ty {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a ReadCell<$ty>>>(iter: I) -> $ty {
+                iter.map(ReadCell::get).sum()
+            }
+        }
+
+        /// Multiplies the values copied from cells, same as for `&T`.
+        ///
+        /// Each cell is read when the iterator yields it.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let values = [1, 2, 3, 4].map(|x: u8| x as ", stringify!($ty), ");")]
+        #[doc = concat!("let view: &ReadCell<[", stringify!($ty), "]> = ReadCell::from_ref(&values);")]
+        ///
+        #[doc = concat!("assert_eq!(view.iter().product::<", stringify!($ty), ">(), 24 as ", stringify!($ty), ");")]
+        /// ```
+        impl<'a> Product<&'a ReadCell<$ty>> for $ty {
+            #[inline]
+            fn product<I: Iterator<Item = &'a ReadCell<$ty>>>(iter: I) -> $ty {
+                iter.map(ReadCell::get).product()
+            }
+        }
+    )*};
+}
+
+// `impl<T: Sum> Sum<&ReadCell<T>> for T` is rejected by orphan rules,
+// so these are implemented for primitives only, same as std does for `&T`.
+impl_sum_product!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+);
+
+impl<T> From<T> for ReadCell<T> {
+    /// Creates a new `ReadCell<T>` containing the given value.
+    fn from(t: T) -> ReadCell<T> {
+        ReadCell::new(t)
+    }
+}
+
+impl<T> From<Cell<T>> for ReadCell<T> {
+    /// Creates a new `ReadCell<T>` containing the value of the given `Cell<T>`.
+    #[inline]
+    fn from(cell: Cell<T>) -> ReadCell<T> {
+        ReadCell::new(cell.into_inner())
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a T> for &'a ReadCell<T> {
+    /// Returns a `&ReadCell<T>` from a `&T`, same as [`ReadCell::from_ref`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// fn sum<'a>(cells: impl Into<&'a ReadCell<[u32]>>) -> u32 {
+    ///     cells.into().as_slice_of_cells().iter().map(ReadCell::get).sum()
+    /// }
+    ///
+    /// let plain: &[u32] = &[1, 2, 3];
+    /// let cell: &Cell<[u32]> = &Cell::new([4, 5, 6]);
+    ///
+    /// assert_eq!(sum(plain), 6);
+    /// assert_eq!(sum(cell), 15);
+    /// ```
+    #[inline]
+    fn from(t: &'a T) -> &'a ReadCell<T> {
+        ReadCell::from_ref(t)
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a Cell<T>> for &'a ReadCell<T> {
+    /// Returns a `&ReadCell<T>` from a `&Cell<T>`, same as [`ReadCell::from_cell`].
+    #[inline]
+    fn from(t: &'a Cell<T>) -> &'a ReadCell<T> {
+        ReadCell::from_cell(t)
+    }
+}
+
+impl<T> From<ReadCell<T>> for Cell<T> {
+    /// Creates a new `Cell<T>` containing the value of the given `ReadCell<T>`.
+    #[inline]
+    fn from(cell: ReadCell<T>) -> Cell<T> {
+        cell.into_cell()
+    }
+}
+
+/// Dereferences to the slice of cells, same as [`ReadCell::as_slice_of_cells`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+/// let view: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+///
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.iter().map(ReadCell::get).sum::<i32>(), 6);
+///
+/// cells.as_slice_of_cells()[0].set(4);
+/// assert_eq!(view[0].get(), 4);
+///
+/// let second = view.get(1).unwrap();
+/// cells.as_slice_of_cells()[1].set(5);
+/// assert_eq!(second.get(), 5);
+///
+/// assert!(view.get(3).is_none());
+/// assert!(<&ReadCell<[i32]>>::from(&[][..]).get(0).is_none());
+/// ```
+impl<T> Deref for ReadCell<[T]> {
+    type Target = [ReadCell<T>];
+
+    #[inline]
+    fn deref(&self) -> &[ReadCell<T>] {
+        self.as_slice_of_cells()
+    }
+}
+
+/// Returns a cell of the element at `index`.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+/// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+///
+/// let second: &ReadCell<i32> = &cell_slice[1];
+/// cells.as_slice_of_cells()[1].set(4);
+/// assert_eq!(second.get(), 4);
+///
+/// let middle: &ReadCell<[i32]> = &cell_slice[1..2];
+/// assert_eq!(middle, &[4]);
+/// assert_eq!(&cell_slice[..1], &[1]);
+/// assert_eq!(&cell_slice[1..], &[4, 3]);
+/// assert_eq!(&cell_slice[..], &[1, 4, 3]);
+/// assert_eq!(&cell_slice[..=1], &[1, 4]);
+/// assert_eq!(&cell_slice[1..=2], &[4, 3]);
+/// assert!(cell_slice[3..].is_empty());
+/// ```
+///
+/// ```should_panic
+/// use read_cell::ReadCell;
+///
+/// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+/// let _ = &cell_slice[2..4];
+/// ```
+///
+/// ```should_panic
+/// use read_cell::ReadCell;
+///
+/// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+/// let _ = &cell_slice[3];
+/// ```
+impl<T> Index<usize> for ReadCell<[T]> {
+    type Output = ReadCell<T>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &ReadCell<T> {
+        &self.as_slice_of_cells()[index]
+    }
+}
+
+macro_rules! impl_index_range {
+    ($($range:ty),* $(,)?) => {$(
+        #[doc = concat!("Returns a sub-slice view for `", stringify!($range), "`.")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if the range is out of bounds, same as for slices.
+        impl<T> Index<$range> for ReadCell<[T]> {
+            type Output = ReadCell<[T]>;
+
+            #[inline]
+            fn index(&self, index: $range) -> &ReadCell<[T]> {
+                ReadCell::from_slice_of_cells(&self.as_slice_of_cells()[index])
+            }
+        }
+
+        #[doc = concat!("Returns a sub-slice view for `", stringify!($range), "`.")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if the range is out of bounds, same as for arrays.
+        impl<T, const N: usize> Index<$range> for ReadCell<[T; N]> {
+            type Output = ReadCell<[T]>;
+
+            #[inline]
+            fn index(&self, index: $range) -> &ReadCell<[T]> {
+                ReadCell::from_slice_of_cells(&self.as_array_of_cells()[index])
+            }
+        }
+    )*};
+}
+
+impl_index_range!(
+    Range<usize>,
+    RangeTo<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+);
+
+/// Iterates over cells of the elements, same as [`ReadCell::iter`].
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+///
+/// let mut sum = 0;
+/// for cell in cell_slice {
+///     sum += cell.get();
+/// }
+/// assert_eq!(sum, 6);
+/// ```
+impl<'a, T> IntoIterator for &'a ReadCell<[T]> {
+    type Item = &'a ReadCell<T>;
+    type IntoIter = slice::Iter<'a, ReadCell<T>>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, ReadCell<T>> {
+        self.iter()
+    }
+}
+
+/// Dereferences to the array of cells, same as [`ReadCell::as_array_of_cells`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cells = Cell::new([1, 2, 3]);
+/// let view: &ReadCell<[i32; 3]> = ReadCell::from_cell(&cells);
+///
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.iter().map(ReadCell::get).sum::<i32>(), 6);
+///
+/// cells.as_array_of_cells()[2].set(4);
+/// assert_eq!(view[2].get(), 4);
+/// ```
+impl<T, const N: usize> Deref for ReadCell<[T; N]> {
+    type Target = [ReadCell<T>; N];
+
+    #[inline]
+    fn deref(&self) -> &[ReadCell<T>; N] {
+        self.as_array_of_cells()
+    }
+}
+
+/// Returns a cell of the element at `index`.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let cells = Cell::new([1, 2, 3, 4, 5]);
+/// let cell_array: &ReadCell<[i32; 5]> = ReadCell::from_cell(&cells);
+///
+/// let fourth: &ReadCell<i32> = &cell_array[3];
+/// cells.as_array_of_cells()[3].set(6);
+/// assert_eq!(fourth.get(), 6);
+///
+/// let middle: &ReadCell<[i32]> = &cell_array[1..4];
+/// assert_eq!(middle, &[2, 3, 6]);
+/// assert_eq!(&cell_array[..2], &[1, 2]);
+/// assert_eq!(&cell_array[3..], &[6, 5]);
+/// assert_eq!(&cell_array[..], &[1, 2, 3, 6, 5]);
+/// assert_eq!(&cell_array[..=1], &[1, 2]);
+/// assert_eq!(&cell_array[2..=4], &[3, 6, 5]);
+/// assert!(cell_array[5..].is_empty());
+///
+/// cells.as_array_of_cells()[1].set(7);
+/// assert_eq!(middle, &[7, 3, 6]);
+/// ```
+///
+/// ```should_panic
+/// use read_cell::ReadCell;
+///
+/// let cell_array: &ReadCell<[i32; 5]> = &ReadCell::new([1, 2, 3, 4, 5]);
+/// let _ = &cell_array[std::hint::black_box(5)];
+/// ```
+///
+/// ```should_panic
+/// use read_cell::ReadCell;
+///
+/// let cell_array: &ReadCell<[i32; 5]> = &ReadCell::new([1, 2, 3, 4, 5]);
+/// let _ = &cell_array[3..std::hint::black_box(6)];
+/// ```
+impl<T, const N: usize> Index<usize> for ReadCell<[T; N]> {
+    type Output = ReadCell<T>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &ReadCell<T> {
+        &self.as_array_of_cells()[index]
+    }
+}
+
+/// Iterates over cells of the elements.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let cell_array = &ReadCell::new([1u8, 2, 3, 4]);
+///
+/// let values: Vec<u8> = cell_array.into_iter().map(ReadCell::get).collect();
+/// assert_eq!(values, [1, 2, 3, 4]);
+///
+/// for cell in cell_array {
+///     assert!(cell.get() > 0);
+/// }
+/// ```
+impl<'a, T, const N: usize> IntoIterator for &'a ReadCell<[T; N]> {
+    type Item = &'a ReadCell<T>;
+    type IntoIter = slice::Iter<'a, ReadCell<T>>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, ReadCell<T>> {
+        self.as_array_of_cells().iter()
+    }
+}
+
+impl<T> ReadCell<T> {
+    /// Creates a new `ReadCell` containing the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    /// ```
+    #[inline]
+    pub const fn new(value: T) -> ReadCell<T> {
+        ReadCell {
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Unwraps the value.
+    ///
+    /// This is a `const fn` on stable Rust, same as [`Cell::into_inner`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    /// let five = c.into_inner();
+    ///
+    /// assert_eq!(five, 5);
+    ///
+    /// const FIVE: i32 = ReadCell::new(5).into_inner();
+    /// assert_eq!(FIVE, 5);
+    ///
+    /// const EMPTY: String = ReadCell::new(String::new()).into_inner();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    pub const fn into_inner(self) -> T {
+        // Moving the value out of `self` directly is not yet allowed in `const fn`
+        // for types with drop glue.
+        // Reading it through a pointer instead requires `const` `ptr::read`,
+        // which is available on the crate's MSRV (see `rust-version` in Cargo.toml).
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and `this` is never dropped.
+        unsafe { ptr::read(ptr::addr_of!(this).cast::<T>()) }
+    }
+
+    /// Converts the `ReadCell` into a [`Cell`] containing the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let frozen: ReadCell<String> = Cell::new(String::from("draft")).into();
+    /// let cell: Cell<String> = frozen.into_cell();
+    ///
+    /// cell.set(String::from("final"));
+    /// let frozen: ReadCell<String> = cell.into();
+    ///
+    /// assert_eq!(Cell::<String>::from(frozen).into_inner(), "final");
+    /// ```
+    #[inline]
+    pub fn into_cell(self) -> Cell<T> {
+        Cell::new(self.value.into_inner())
+    }
+
+    /// Returns a `&[ReadCell<T>]` from a `&[T]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &[ReadCell<i32>] = ReadCell::from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(cells.iter().map(ReadCell::get).sum::<i32>(), 6);
+    /// ```
+    #[inline]
+    pub const fn from_slice(slice: &[T]) -> &[ReadCell<T>] {
+        ReadCell::from_ref(slice).as_slice_of_cells()
+    }
+
+    /// Returns a `&mut [ReadCell<T>]` from a `&mut [T]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &mut [i32] = &mut [1, 2, 3];
+    /// let cells: &mut [ReadCell<i32>] = ReadCell::from_mut_slice(slice);
+    ///
+    /// *cells[1].get_mut() = 4;
+    /// assert_eq!(slice, [1, 4, 3]);
+    /// ```
+    #[inline]
+    pub fn from_mut_slice(slice: &mut [T]) -> &mut [ReadCell<T>] {
+        ReadCell::from_mut(slice).as_mut_slice_of_cells()
+    }
+
+    /// Returns a `&[ReadCell<T>]` from a `&[Cell<T>]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells = [Cell::new(1), Cell::new(2), Cell::new(3)];
+    /// let view: &[ReadCell<i32>] = ReadCell::from_cell_slice(&cells);
+    ///
+    /// cells[1].set(4);
+    /// assert_eq!(view[1].get(), 4);
+    /// ```
+    #[inline]
+    pub const fn from_cell_slice(slice: &[Cell<T>]) -> &[ReadCell<T>] {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `Cell<T>`
+        // and is more restricted.
+        unsafe { &*(slice as *const [Cell<T>] as *const [ReadCell<T>]) }
+    }
+
+    /// Returns a `&ReadCell<U>` viewing the same value as `U`.
+    ///
+    /// `U` must have the same size as `T` and alignment not greater than `T`'s.
+    /// This is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// Every value the cell may contain while the returned reference is alive,
+    /// including values stored through an aliasing [`Cell`], must be a valid `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let register = Cell::new(0x0102_0304u32);
+    /// let view = ReadCell::from_cell(&register);
+    ///
+    /// // SAFETY: Any bit pattern is a valid `[u8; 4]`.
+    /// let bytes: &ReadCell<[u8; 4]> = unsafe { view.cast() };
+    ///
+    /// if cfg!(target_endian = "little") {
+    ///     assert_eq!(bytes.get(), [4, 3, 2, 1]);
+    /// } else {
+    ///     assert_eq!(bytes.get(), [1, 2, 3, 4]);
+    /// }
+    ///
+    /// register.set(u32::from_ne_bytes([5, 6, 7, 8]));
+    /// assert_eq!(bytes.get(), [5, 6, 7, 8]);
+    /// ```
+    ///
+    /// Size mismatch is rejected.
+    ///
+    /// ```compile_fail
+    /// use read_cell::ReadCell;
+    ///
+    /// let register = ReadCell::new(0u32);
+    /// let _: &ReadCell<[u8; 2]> = unsafe { register.cast() };
+    /// ```
+    #[inline]
+    pub unsafe fn cast<U>(&self) -> &ReadCell<U> {
+        let () = AssertCast::<T, U>::LAYOUT_MATCHES;
+
+        // SAFETY: Layout is checked above.
+        // Caller guarantees that values are valid `U`.
+        unsafe { self.cast_unchecked() }
+    }
+
+    /// Returns a `&ReadCell<U>` viewing the same value as `U`, without checking layout.
+    ///
+    /// # Safety
+    ///
+    /// `U` must not be larger than `T`, nor have greater alignment,
+    /// and every value the cell may contain while the returned reference is alive
+    /// must be a valid `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let register = ReadCell::new(u32::from_ne_bytes([1, 2, 3, 4]));
+    ///
+    /// // SAFETY: `[u8; 2]` is smaller than `u32` and any bit pattern is valid.
+    /// let head: &ReadCell<[u8; 2]> = unsafe { register.cast_unchecked() };
+    ///
+    /// assert_eq!(head.get(), [1, 2]);
+    /// ```
+    #[inline]
+    pub unsafe fn cast_unchecked<U>(&self) -> &ReadCell<U> {
+        // SAFETY: Caller guarantees layout compatibility and validity of values.
+        unsafe { &*(self as *const ReadCell<T> as *const ReadCell<U>) }
+    }
+}
+
+struct AssertCast<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> AssertCast<T, U> {
+    const LAYOUT_MATCHES: () = assert!(
+        size_of::<T>() == size_of::<U>() && align_of::<U>() <= align_of::<T>(),
+        "`ReadCell::cast` requires same size and not greater alignment"
+    );
+}
+
+impl<T: Copy> ReadCell<T> {
+    /// Returns a copy of the contained value.
+    ///
+    /// This is a `const fn` on stable Rust, same as [`Cell::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    ///
+    /// let five = c.get();
+    ///
+    /// const TABLE: [u8; 3] = {
+    ///     let c = ReadCell::new([1, 2, 3]);
+    ///     c.get()
+    /// };
+    ///
+    /// const _: () = {
+    ///     assert!(ReadCell::new(5).get() == 5);
+    /// };
+    ///
+    /// const SCALE: ReadCell<u32> = ReadCell::new(3);
+    /// static LOOKUP: [u32; 4] = [0, SCALE.get(), 2 * SCALE.get(), 3 * SCALE.get()];
+    ///
+    /// assert_eq!(TABLE, [1, 2, 3]);
+    /// assert_eq!(LOOKUP, [0, 3, 6, 9]);
+    /// assert_eq!(const { ReadCell::new(7u8).get() }, 7);
+    /// ```
+    ///
+    /// Arrays of `Copy` elements are `Copy` too, so a whole array is copied at once.
+    /// Use [`as_array_of_cells`] to read elements one by one instead.
+    ///
+    /// [`as_array_of_cells`]: `ReadCell::as_array_of_cells`
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let shared = Cell::new([1u16, 2, 3, 4]);
+    /// let view: &ReadCell<[u16; 4]> = ReadCell::from_cell(&shared);
+    ///
+    /// let snapshot: [u16; 4] = view.get();
+    /// shared.set([5, 6, 7, 8]);
+    ///
+    /// assert_eq!(snapshot, [1, 2, 3, 4]);
+    ///
+    /// let [first, .., last] = view.get();
+    /// assert_eq!((first, last), (5, 8));
+    /// ```
+    #[inline]
+    pub const fn get(&self) -> T {
+        // Reading through a raw pointer is allowed in `const fn` on every supported compiler,
+        // so `const` is not gated behind `nightly` feature.
+        //
+        // SAFETY: This can cause data races if called from a separate thread,
+        // but `ReadCell` is `!Sync` so this won't happen.
+        unsafe { *self.value.get() }
+    }
+
+    /// Copies the contained value into `dst` and returns a reference to it.
+    ///
+    /// The value is copied with a single [`ptr::copy_nonoverlapping`] call
+    /// directly into the destination, which avoids intermediate copies of large values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use read_cell::ReadCell;
+    ///
+    /// let frame = Box::new(ReadCell::new([7u8; 65536]));
+    /// let mut dst = Box::new(MaybeUninit::uninit());
+    ///
+    /// let copy: &mut [u8; 65536] = frame.get_to(&mut dst);
+    /// assert!(copy.iter().all(|&b| b == 7));
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct Padded {
+    ///     tag: u8,
+    ///     value: u32,
+    /// }
+    ///
+    /// let c = ReadCell::new(Padded { tag: 1, value: 0xDEAD_BEEF });
+    /// let mut dst = MaybeUninit::uninit();
+    ///
+    /// assert_eq!(*c.get_to(&mut dst), Padded { tag: 1, value: 0xDEAD_BEEF });
+    /// ```
+    #[inline]
+    pub fn get_to<'a>(&self, dst: &'a mut MaybeUninit<T>) -> &'a mut T {
+        // SAFETY: `dst` is valid for writes and can't overlap with the cell
+        // since it is borrowed mutably. `ReadCell` is `!Sync`.
+        unsafe {
+            ptr::copy_nonoverlapping(self.value.get(), dst.as_mut_ptr(), 1);
+            dst.assume_init_mut()
+        }
+    }
+
+    /// Returns a copy of the contained value using a volatile read.
+    ///
+    /// Volatile reads are never elided or merged by the compiler,
+    /// which is useful for polling memory modified by hardware, e.g. DMA buffers.
+    /// Note that this does not make concurrent modification by other threads or devices
+    /// formally race-free, it only prevents the compiler from caching the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let status = Cell::new(0u32);
+    /// let view = ReadCell::from_cell(&status);
+    ///
+    /// assert_eq!(view.get_volatile(), 0);
+    /// status.set(1);
+    /// assert_eq!(view.get_volatile(), 1);
+    /// ```
+    #[inline]
+    pub fn get_volatile(&self) -> T {
+        // SAFETY: Pointer is valid and aligned. `ReadCell` is `!Sync`.
+        unsafe { ptr::read_volatile(self.value.get()) }
+    }
+
+    /// Returns a new `ReadCell` holding the result of applying `f`
+    /// to a copy of the contained value.
+    ///
+    /// `f` receives the value by copy, no reference into the cell is ever handed out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let counter = Cell::new(5);
+    /// let view = ReadCell::from_cell(&counter);
+    ///
+    /// let doubled: ReadCell<i32> = view.map(|x| x * 2);
+    /// let text: ReadCell<String> = view.map(|x| x.to_string());
+    ///
+    /// counter.set(6);
+    /// assert_eq!(doubled.get(), 10);
+    /// assert_eq!(text.into_inner(), "5");
+    /// ```
+    #[inline]
+    pub fn map<U>(&self, f: impl FnOnce(T) -> U) -> ReadCell<U> {
+        ReadCell::new(f(self.get()))
+    }
+
+    /// Applies `f` to a copy of the contained value, reading the cell once.
+    ///
+    /// `f` receives the value by copy, no reference into the cell is ever handed out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let counter = Cell::new(1);
+    /// let view = ReadCell::from_cell(&counter);
+    ///
+    /// let doubled = view.map_get(|x| {
+    ///     counter.set(x + 1);
+    ///     x * 2
+    /// });
+    ///
+    /// assert_eq!(doubled, 2);
+    /// assert_eq!(view.get(), 2);
+    /// ```
+    #[inline]
+    pub fn map_get<U>(&self, f: impl FnOnce(T) -> U) -> U {
+        f(self.get())
+    }
+
+    /// Returns a copy of the contained value if it satisfies `pred`, reading the cell once.
+    ///
+    /// `pred` receives a reference to the copy, not into the cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let counter = Cell::new(1);
+    /// let view = ReadCell::from_cell(&counter);
+    ///
+    /// let odd = view.get_if(|x| {
+    ///     counter.set(x + 1);
+    ///     x % 2 == 1
+    /// });
+    ///
+    /// assert_eq!(odd, Some(1));
+    /// assert_eq!(view.get_if(|x| x % 2 == 1), None);
+    /// ```
+    #[inline]
+    pub fn get_if(&self, pred: impl FnOnce(&T) -> bool) -> Option<T> {
+        let value = self.get();
+        if pred(&value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if a copy of the contained value satisfies `pred`, reading the cell once.
+    ///
+    /// `pred` receives a reference to the copy, not into the cell.
+    /// This is handy for checking the variant of an enum with [`matches!`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// enum State {
+    ///     Idle,
+    ///     Loading { progress: u8 },
+    ///     Failed(i32),
+    /// }
+    ///
+    /// let state = Cell::new(State::Loading { progress: 10 });
+    /// let view = ReadCell::from_cell(&state);
+    ///
+    /// assert!(view.get_matches(|s| matches!(s, State::Loading { progress } if *progress < 50)));
+    ///
+    /// state.set(State::Failed(-1));
+    /// assert!(view.get_matches(|s| matches!(s, State::Failed(_))));
+    /// assert!(!view.get_matches(|s| matches!(s, State::Idle)));
+    /// ```
+    #[inline]
+    pub fn get_matches(&self, pred: impl FnOnce(&T) -> bool) -> bool {
+        pred(&self.get())
+    }
+
+    /// Returns the discriminant of a copy of the contained value.
+    ///
+    /// Discriminants are equal if the values are the same enum variant,
+    /// regardless of the data they carry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, mem::discriminant};
+    /// use read_cell::ReadCell;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// enum State {
+    ///     Idle,
+    ///     Loading { progress: u8 },
+    ///     Failed(i32),
+    /// }
+    ///
+    /// let state = Cell::new(State::Loading { progress: 10 });
+    /// let view = ReadCell::from_cell(&state);
+    ///
+    /// let loading = view.discriminant();
+    /// assert_eq!(loading, discriminant(&State::Loading { progress: 99 }));
+    /// assert_ne!(loading, discriminant(&State::Idle));
+    ///
+    /// state.set(State::Loading { progress: 20 });
+    /// assert_eq!(view.discriminant(), loading);
+    ///
+    /// state.set(State::Failed(-1));
+    /// assert_ne!(view.discriminant(), loading);
+    /// assert_eq!(view.discriminant(), discriminant(&State::Failed(0)));
+    /// ```
+    #[inline]
+    pub fn discriminant(&self) -> Discriminant<T> {
+        mem::discriminant(&self.get())
+    }
+}
+
+impl<T: Copy + PartialEq> ReadCell<T> {
+    /// Returns `true` if the contained value is equal to `value`, reading the cell once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// #[derive(Clone, Copy, PartialEq)]
+    /// enum State {
+    ///     Idle,
+    ///     Loading { progress: u8 },
+    /// }
+    ///
+    /// let state = Cell::new(State::Idle);
+    /// let view = ReadCell::from_cell(&state);
+    ///
+    /// assert!(view.is(State::Idle));
+    ///
+    /// state.set(State::Loading { progress: 10 });
+    /// assert!(view.is(State::Loading { progress: 10 }));
+    /// assert!(!view.is(State::Loading { progress: 20 }));
+    /// ```
+    #[inline]
+    pub fn is(&self, value: T) -> bool {
+        self.get() == value
+    }
+}
+
+macro_rules! impl_float {
+    ($($ty:ident),* $(,)?) => {$(
+        impl PartialEq for TotalOrdered<$ty> {
+            #[inline]
+            fn eq(&self, other: &TotalOrdered<$ty>) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for TotalOrdered<$ty> {}
+
+        impl PartialOrd for TotalOrdered<$ty> {
+            #[inline]
+            fn partial_cmp(&self, other: &TotalOrdered<$ty>) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for TotalOrdered<$ty> {
+            #[inline]
+            fn cmp(&self, other: &TotalOrdered<$ty>) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for TotalOrdered<$ty> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl ReadCell<$ty> {
+            /// Returns `true` if the contained value is NaN.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("assert!(ReadCell::new(", stringify!($ty), "::NAN).is_nan());")]
+            #[doc = concat!("assert!(!ReadCell::new(1.0", stringify!($ty), ").is_nan());")]
+            /// ```
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.get().is_nan()
+            }
+
+            /// Returns `true` if the contained value is neither infinite nor NaN.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("assert!(ReadCell::new(-0.0", stringify!($ty), ").is_finite());")]
+            #[doc = concat!("assert!(!ReadCell::new(", stringify!($ty), "::INFINITY).is_finite());")]
+            #[doc = concat!("assert!(!ReadCell::new(", stringify!($ty), "::NAN).is_finite());")]
+            /// ```
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.get().is_finite()
+            }
+
+            #[doc = concat!("Compares the contained values with [`", stringify!($ty), "::total_cmp`].")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cmp::Ordering;
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let values = [", stringify!($ty), "::NEG_INFINITY, -0.0, 0.0, 1.0, ", stringify!($ty), "::INFINITY, ", stringify!($ty), "::NAN];")]
+            ///
+            /// for a in values {
+            ///     for b in values {
+            ///         assert_eq!(ReadCell::new(a).total_cmp(&ReadCell::new(b)), a.total_cmp(&b));
+            ///     }
+            /// }
+            ///
+            #[doc = concat!("assert_eq!(ReadCell::new(-0.0", stringify!($ty), ").total_cmp(&ReadCell::new(0.0)), Ordering::Less);")]
+            /// ```
+            #[inline]
+            pub fn total_cmp(&self, other: &ReadCell<$ty>) -> Ordering {
+                self.get().total_cmp(&other.get())
+            }
+
+            /// Returns a copy of the contained value ordered with `total_cmp`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use read_cell::{ReadCell, TotalOrdered};
+            ///
+            #[doc = concat!("let cells = [", stringify!($ty), "::NAN, 0.0, -0.0, ", stringify!($ty), "::NEG_INFINITY].map(ReadCell::new);")]
+            ///
+            /// let mut snapshot: Vec<_> = cells.iter().map(|c| c.get_total_ordered()).collect();
+            /// snapshot.sort();
+            ///
+            #[doc = concat!("assert_eq!(snapshot[..3], [TotalOrdered(", stringify!($ty), "::NEG_INFINITY), TotalOrdered(-0.0), TotalOrdered(0.0)]);")]
+            /// assert!(snapshot[3].0.is_nan());
+            /// ```
+            #[inline]
+            pub fn get_total_ordered(&self) -> TotalOrdered<$ty> {
+                TotalOrdered(self.get())
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+macro_rules! forward_get {
+    ($ty:ident => $($method:ident($($arg:ident: $arg_ty:ty),*) -> $ret:ty;)*) => {$(
+        #[doc = concat!("Reads the cell once and returns [`", stringify!($ty), "::", stringify!($method), "`] of the value.")]
+        #[inline]
+        pub fn $method(&self, $($arg: $arg_ty),*) -> $ret {
+            self.get().$method($($arg),*)
+        }
+    )*};
+}
+
+/// Character classification and conversion, reading the cell once per call.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let key = Cell::new('a');
+/// let last_key = ReadCell::from_cell(&key);
+///
+/// let expected = [
+///     // (char, alphabetic, numeric, whitespace, uppercase, hex digit)
+///     ('a', true, false, false, 'A', Some(10)),
+///     ('Z', true, false, false, 'Z', None),
+///     ('7', false, true, false, '7', Some(7)),
+///     (' ', false, false, true, ' ', None),
+///     ('\n', false, false, true, '\n', None),
+///     ('ß', true, false, false, 'ß', None),
+///     ('½', false, true, false, '½', None),
+///     ('🦀', false, false, false, '🦀', None),
+/// ];
+///
+/// for (c, alphabetic, numeric, whitespace, upper, digit) in expected {
+///     key.set(c);
+///     assert_eq!(last_key.is_alphabetic(), alphabetic);
+///     assert_eq!(last_key.is_numeric(), numeric);
+///     assert_eq!(last_key.is_whitespace(), whitespace);
+///     assert_eq!(last_key.to_ascii_uppercase(), upper);
+///     assert_eq!(last_key.to_digit(16), digit);
+/// }
+/// ```
+impl ReadCell<char> {
+    forward_get! {
+        char =>
+        is_alphabetic() -> bool;
+        is_numeric() -> bool;
+        is_whitespace() -> bool;
+        to_ascii_uppercase() -> char;
+        to_digit(radix: u32) -> Option<u32>;
+    }
+}
+
+/// ASCII classification and conversion, reading the cell once per call.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// let byte = Cell::new(0u8);
+/// let view = ReadCell::from_cell(&byte);
+///
+/// let expected = [
+///     // (byte, ascii, digit, lowercase, equal to b'q' ignoring case)
+///     (b'Q', true, false, b'q', true),
+///     (b'q', true, false, b'q', true),
+///     (b'0', true, true, b'0', false),
+///     (b' ', true, false, b' ', false),
+///     (0x7F, true, false, 0x7F, false),
+///     (0x80, false, false, 0x80, false),
+///     (0xFF, false, false, 0xFF, false),
+/// ];
+///
+/// for (b, ascii, digit, lower, is_q) in expected {
+///     byte.set(b);
+///     assert_eq!(view.is_ascii(), ascii);
+///     assert_eq!(view.is_ascii_digit(), digit);
+///     assert_eq!(view.to_ascii_lowercase(), lower);
+///     assert_eq!(view.eq_ignore_ascii_case(b'q'), is_q);
+/// }
+/// ```
+impl ReadCell<u8> {
+    forward_get! {
+        u8 =>
+        is_ascii() -> bool;
+        is_ascii_digit() -> bool;
+        to_ascii_lowercase() -> u8;
+    }
+
+    /// Reads the cell once and returns [`u8::eq_ignore_ascii_case`] of the value and `other`.
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, other: u8) -> bool {
+        self.get().eq_ignore_ascii_case(&other)
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ident),* $(,)?) => {$(
+        /// Bit queries and rotations, reading the cell once per call.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::cell::Cell;
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let counter = Cell::<", stringify!($ty), ">::new(0);")]
+        /// let view = ReadCell::from_cell(&counter);
+        ///
+        #[doc = concat!("assert_eq!(view.leading_zeros(), ", stringify!($ty), "::BITS);")]
+        #[doc = concat!("assert_eq!(view.trailing_zeros(), ", stringify!($ty), "::BITS);")]
+        /// assert_eq!(view.count_ones(), 0);
+        #[doc = concat!("assert_eq!(view.count_zeros(), ", stringify!($ty), "::BITS);")]
+        /// assert!(!view.is_power_of_two());
+        /// assert_eq!(view.checked_ilog2(), None);
+        ///
+        #[doc = concat!("counter.set(", stringify!($ty), "::MAX);")]
+        /// assert_eq!(view.leading_zeros(), 0);
+        /// assert_eq!(view.trailing_zeros(), 0);
+        #[doc = concat!("assert_eq!(view.count_ones(), ", stringify!($ty), "::BITS);")]
+        /// assert_eq!(view.count_zeros(), 0);
+        /// assert!(!view.is_power_of_two());
+        #[doc = concat!("assert_eq!(view.checked_ilog2(), Some(", stringify!($ty), "::BITS - 1));")]
+        #[doc = concat!("assert_eq!(view.rotate_left(3), ", stringify!($ty), "::MAX);")]
+        ///
+        /// counter.set(1);
+        /// assert!(view.is_power_of_two());
+        /// assert_eq!(view.checked_ilog2(), Some(0));
+        /// assert_eq!(view.rotate_left(3), 8);
+        #[doc = concat!("assert_eq!(view.rotate_right(1), 1 << (", stringify!($ty), "::BITS - 1));")]
+        /// ```
+        impl ReadCell<$ty> {
+            forward_get! {
+                $ty =>
+                leading_zeros() -> u32;
+                trailing_zeros() -> u32;
+                count_ones() -> u32;
+                count_zeros() -> u32;
+                is_power_of_two() -> bool;
+                checked_ilog2() -> Option<u32>;
+                rotate_left(n: u32) -> $ty;
+                rotate_right(n: u32) -> $ty;
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_signed {
+    ($($ty:ident),* $(,)?) => {$(
+        /// Bit queries, rotations and sign helpers, reading the cell once per call.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::cell::Cell;
+        /// use read_cell::ReadCell;
+        ///
+        #[doc = concat!("let counter = Cell::<", stringify!($ty), ">::new(0);")]
+        /// let view = ReadCell::from_cell(&counter);
+        ///
+        #[doc = concat!("assert_eq!(view.leading_zeros(), ", stringify!($ty), "::BITS);")]
+        #[doc = concat!("assert_eq!(view.trailing_zeros(), ", stringify!($ty), "::BITS);")]
+        /// assert_eq!(view.count_ones(), 0);
+        /// assert_eq!(view.checked_ilog2(), None);
+        /// assert_eq!(view.abs(), 0);
+        /// assert_eq!(view.signum(), 0);
+        ///
+        #[doc = concat!("counter.set(", stringify!($ty), "::MAX);")]
+        /// assert_eq!(view.leading_zeros(), 1);
+        #[doc = concat!("assert_eq!(view.count_ones(), ", stringify!($ty), "::BITS - 1);")]
+        #[doc = concat!("assert_eq!(view.checked_ilog2(), Some(", stringify!($ty), "::BITS - 2));")]
+        #[doc = concat!("assert_eq!(view.abs(), ", stringify!($ty), "::MAX);")]
+        /// assert_eq!(view.signum(), 1);
+        ///
+        /// counter.set(-1);
+        #[doc = concat!("assert_eq!(view.count_ones(), ", stringify!($ty), "::BITS);")]
+        /// assert_eq!(view.count_zeros(), 0);
+        /// assert_eq!(view.checked_ilog2(), None);
+        /// assert_eq!(view.rotate_left(5), -1);
+        /// assert_eq!(view.abs(), 1);
+        /// assert_eq!(view.signum(), -1);
+        ///
+        #[doc = concat!("counter.set(", stringify!($ty), "::MIN);")]
+        #[doc = concat!("assert_eq!(view.trailing_zeros(), ", stringify!($ty), "::BITS - 1);")]
+        /// assert_eq!(view.rotate_left(1), 1);
+        #[doc = concat!("assert_eq!(view.rotate_right(1), 1 << (", stringify!($ty), "::BITS - 2));")]
+        /// ```
+        impl ReadCell<$ty> {
+            forward_get! {
+                $ty =>
+                leading_zeros() -> u32;
+                trailing_zeros() -> u32;
+                count_ones() -> u32;
+                count_zeros() -> u32;
+                checked_ilog2() -> Option<u32>;
+                rotate_left(n: u32) -> $ty;
+                rotate_right(n: u32) -> $ty;
+                abs() -> $ty;
+                signum() -> $ty;
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_endian {
+    ($($ty:ident),* $(,)?) => {$(
+        impl ReadCell<$ty> {
+            /// Returns a copy of the contained value, converting it from big endian.
+            ///
+            /// On big endian targets this is the same as [`get`](ReadCell::get),
+            /// on little endian targets the bytes are swapped.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let bytes: [u8; ", stringify!($ty), "::BITS as usize / 8] = core::array::from_fn(|i| i as u8 + 1);")]
+            #[doc = concat!("let raw = Cell::new(", stringify!($ty), "::from_ne_bytes(bytes));")]
+            /// let counter = ReadCell::from_cell(&raw);
+            ///
+            #[doc = concat!("assert_eq!(counter.get_be(), ", stringify!($ty), "::from_be_bytes(bytes));")]
+            /// ```
+            #[inline]
+            pub fn get_be(&self) -> $ty {
+                $ty::from_be(self.get())
+            }
+
+            /// Returns a copy of the contained value, converting it from little endian.
+            ///
+            /// On little endian targets this is the same as [`get`](ReadCell::get),
+            /// on big endian targets the bytes are swapped.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let bytes: [u8; ", stringify!($ty), "::BITS as usize / 8] = core::array::from_fn(|i| i as u8 + 1);")]
+            #[doc = concat!("let raw = Cell::new(", stringify!($ty), "::from_ne_bytes(bytes));")]
+            /// let counter = ReadCell::from_cell(&raw);
+            ///
+            #[doc = concat!("assert_eq!(counter.get_le(), ", stringify!($ty), "::from_le_bytes(bytes));")]
+            /// ```
+            #[inline]
+            pub fn get_le(&self) -> $ty {
+                $ty::from_le(self.get())
+            }
+
+            /// Returns a copy of the contained value in native endian.
+            ///
+            /// Same as [`get`](ReadCell::get), provided for symmetry with
+            /// [`get_be`](ReadCell::get_be) and [`get_le`](ReadCell::get_le).
+            #[inline]
+            pub fn get_ne(&self) -> $ty {
+                self.get()
+            }
+        }
+    )*};
+}
+
+impl_endian!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_endian_bytes {
+    ($($ty:ident => $be:ident, $le:ident, $ne:ident;)*) => {$(
+        impl ReadCell<[u8; size_of::<$ty>()]> {
+            #[doc = concat!("Reads the bytes as a big endian `", stringify!($ty), "`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let bytes: [u8; ", stringify!($ty), "::BITS as usize / 8] = core::array::from_fn(|i| i as u8 + 1);")]
+            ///
+            #[doc = concat!("assert_eq!(ReadCell::new(bytes).", stringify!($be), "(), ", stringify!($ty), "::from_be_bytes(bytes));")]
+            #[doc = concat!("assert_eq!(ReadCell::new(bytes).", stringify!($be), "() & 0xFF, bytes.len() as ", stringify!($ty), ");")]
+            /// ```
+            #[inline]
+            pub fn $be(&self) -> $ty {
+                $ty::from_be_bytes(self.get())
+            }
+
+            #[doc = concat!("Reads the bytes as a little endian `", stringify!($ty), "`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let bytes: [u8; ", stringify!($ty), "::BITS as usize / 8] = core::array::from_fn(|i| i as u8 + 1);")]
+            ///
+            #[doc = concat!("assert_eq!(ReadCell::new(bytes).", stringify!($le), "(), ", stringify!($ty), "::from_le_bytes(bytes));")]
+            #[doc = concat!("assert_eq!(ReadCell::new(bytes).", stringify!($le), "() & 0xFF, 1);")]
+            /// ```
+            #[inline]
+            pub fn $le(&self) -> $ty {
+                $ty::from_le_bytes(self.get())
+            }
+
+            #[doc = concat!("Reads the bytes as a native endian `", stringify!($ty), "`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let raw = Cell::new(", stringify!($ty), "::MAX / 3);")]
+            ///
+            /// // SAFETY: Any bit pattern is a valid byte array.
+            #[doc = concat!("let bytes: &ReadCell<[u8; ", stringify!($ty), "::BITS as usize / 8]> = unsafe { ReadCell::from_cell(&raw).cast() };")]
+            ///
+            #[doc = concat!("assert_eq!(bytes.", stringify!($ne), "(), ", stringify!($ty), "::MAX / 3);")]
+            /// ```
+            #[inline]
+            pub fn $ne(&self) -> $ty {
+                $ty::from_ne_bytes(self.get())
+            }
+        }
+    )*};
+}
+
+impl_endian_bytes! {
+    u16 => get_u16_be, get_u16_le, get_u16_ne;
+    u32 => get_u32_be, get_u32_le, get_u32_ne;
+    u64 => get_u64_be, get_u64_le, get_u64_ne;
+    u128 => get_u128_be, get_u128_le, get_u128_ne;
+}
+
+macro_rules! impl_non_zero {
+    ($($nz:ident => $ty:ident),* $(,)?) => {$(
+        impl ReadCell<$nz> {
+            #[doc = concat!("Returns the contained value as a primitive `", stringify!($ty), "`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            #[doc = concat!("use std::num::", stringify!($nz), ";")]
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let len = Cell::new(", stringify!($nz), "::new(1).unwrap());")]
+            /// let view = ReadCell::from_cell(&len);
+            /// assert_eq!(view.get_raw(), 1);
+            ///
+            #[doc = concat!("len.set(", stringify!($nz), "::MAX);")]
+            #[doc = concat!("assert_eq!(view.get_raw(), ", stringify!($ty), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn get_raw(&self) -> $ty {
+                self.get().get()
+            }
+        }
+
+        impl ReadCell<Option<$nz>> {
+            #[doc = concat!("Returns the contained value as a primitive `", stringify!($ty), "`, or `0` if it is `None`.")]
+            ///
+            #[doc = concat!("`Option<", stringify!($nz), ">` has the same layout as `", stringify!($ty), "`,")]
+            /// with `None` represented by zero, so this is a plain read.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::{cell::Cell, mem::size_of};
+            #[doc = concat!("use std::num::", stringify!($nz), ";")]
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("assert_eq!(size_of::<ReadCell<Option<", stringify!($nz), ">>>(), size_of::<", stringify!($ty), ">());")]
+            ///
+            #[doc = concat!("let handle = Cell::new(None::<", stringify!($nz), ">);")]
+            /// let view = ReadCell::from_cell(&handle);
+            /// assert_eq!(view.get_raw(), 0);
+            ///
+            #[doc = concat!("handle.set(", stringify!($nz), "::new(7));")]
+            /// assert_eq!(view.get_raw(), 7);
+            ///
+            #[doc = concat!("handle.set(", stringify!($nz), "::new(0));")]
+            /// assert_eq!(view.get(), None);
+            /// assert_eq!(view.get_raw(), 0);
+            /// ```
+            #[inline]
+            pub fn get_raw(&self) -> $ty {
+                match self.get() {
+                    Some(value) => value.get(),
+                    None => 0,
+                }
+            }
+
+            /// Returns `true` if the contained value is non-zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            #[doc = concat!("use std::num::", stringify!($nz), ";")]
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = concat!("let handle = Cell::new(None::<", stringify!($nz), ">);")]
+            /// let view = ReadCell::from_cell(&handle);
+            /// assert!(!view.is_set());
+            ///
+            #[doc = concat!("handle.set(", stringify!($nz), "::new(1));")]
+            /// assert!(view.is_set());
+            /// ```
+            #[inline]
+            pub fn is_set(&self) -> bool {
+                self.get().is_some()
+            }
+        }
+    )*};
+}
+
+impl_non_zero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+);
+
+impl ReadCell<bool> {
+    /// Returns `Some(f())` if the contained value is `true`, or `None` otherwise.
+    ///
+    /// The cell is read once, before `f` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let visible = Cell::new(true);
+    /// let flag = ReadCell::from_cell(&visible);
+    ///
+    /// let hidden = flag.then(|| {
+    ///     visible.set(false);
+    ///     "hidden"
+    /// });
+    ///
+    /// assert_eq!(hidden, Some("hidden"));
+    /// assert_eq!(flag.then(|| "hidden again"), None);
+    /// ```
+    #[inline]
+    pub fn then<U>(&self, f: impl FnOnce() -> U) -> Option<U> {
+        self.get().then(f)
+    }
+
+    /// Returns `Some(value)` if the contained value is `true`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(true).then_some(5), Some(5));
+    /// assert_eq!(ReadCell::new(false).then_some(5), None);
+    /// ```
+    #[inline]
+    pub fn then_some<U>(&self, value: U) -> Option<U> {
+        self.get().then_some(value)
+    }
+
+    /// Returns logical AND of the contained values.
+    ///
+    /// Short-circuits same as `&&`: `other` is not read if this cell contains `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let yes = ReadCell::new(true);
+    /// let no = ReadCell::new(false);
+    ///
+    /// assert!(yes.and(&yes));
+    /// assert!(!yes.and(&no));
+    /// assert!(!no.and(&yes));
+    /// assert!(!no.and(&no));
+    /// ```
+    #[inline]
+    pub fn and(&self, other: &ReadCell<bool>) -> bool {
+        self.get() && other.get()
+    }
+
+    /// Returns logical OR of the contained values.
+    ///
+    /// Short-circuits same as `||`: `other` is not read if this cell contains `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let yes = ReadCell::new(true);
+    /// let no = ReadCell::new(false);
+    ///
+    /// assert!(yes.or(&yes));
+    /// assert!(yes.or(&no));
+    /// assert!(no.or(&yes));
+    /// assert!(!no.or(&no));
+    /// ```
+    #[inline]
+    pub fn or(&self, other: &ReadCell<bool>) -> bool {
+        self.get() || other.get()
+    }
+
+    /// Returns logical XOR of the contained values.
+    ///
+    /// Both cells are always read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let yes = ReadCell::new(true);
+    /// let no = ReadCell::new(false);
+    ///
+    /// assert!(!yes.xor(&yes));
+    /// assert!(yes.xor(&no));
+    /// assert!(no.xor(&yes));
+    /// assert!(!no.xor(&no));
+    /// ```
+    #[inline]
+    pub fn xor(&self, other: &ReadCell<bool>) -> bool {
+        self.get() ^ other.get()
+    }
+
+    /// Returns logical negation of the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let muted = Cell::new(false);
+    /// let flag = ReadCell::from_cell(&muted);
+    ///
+    /// assert!(flag.not());
+    ///
+    /// muted.set(true);
+    /// assert!(!flag.not());
+    /// ```
+    #[inline]
+    pub fn not(&self) -> bool {
+        !self.get()
+    }
+}
+
+impl<T: Copy + Ord> ReadCell<T> {
+    /// Returns the minimum of the contained value and `other`, reading the cell once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    ///
+    /// assert_eq!(c.min_get(3), 3);
+    /// assert_eq!(c.min_get(7), 5);
+    /// ```
+    #[inline]
+    pub fn min_get(&self, other: T) -> T {
+        self.get().min(other)
+    }
+
+    /// Returns the maximum of the contained value and `other`, reading the cell once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    ///
+    /// assert_eq!(c.max_get(3), 5);
+    /// assert_eq!(c.max_get(7), 7);
+    /// ```
+    #[inline]
+    pub fn max_get(&self, other: T) -> T {
+        self.get().max(other)
+    }
+}
+
+impl<T: Copy + PartialOrd> ReadCell<T> {
+    /// Returns the contained value restricted to `min..=max`, reading the cell once.
+    ///
+    /// Works for floats too. If the contained value is NaN, NaN is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max` or either bound is not comparable to itself, e.g. NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(5).clamp_get(0, 3), 3);
+    /// assert_eq!(ReadCell::new(-1.5).clamp_get(0.0, 1.0), 0.0);
+    /// assert!(ReadCell::new(f32::NAN).clamp_get(0.0, 1.0).is_nan());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// ReadCell::new(5).clamp_get(3, 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// ReadCell::new(0.5).clamp_get(f64::NAN, 1.0);
+    /// ```
+    #[inline]
+    pub fn clamp_get(&self, min: T, max: T) -> T {
+        assert!(min <= max, "min must be less than or equal to max");
+
+        let value = self.get();
+        if value < min {
+            min
+        } else if value > max {
+            max
+        } else {
+            value
+        }
+    }
+}
+
+impl<T: Clone> ReadCell<T> {
+    /// Returns a clone of the contained value.
+    ///
+    /// Unlike [`get`], this works for values that are [`Clone`] but not [`Copy`].
+    /// There's no safe counterpart, because [`Clone::clone`] receives a reference
+    /// to the inner value and runs arbitrary code.
+    /// If that code modifies the value through an aliasing [`Cell`],
+    /// for example one stored in a thread local, the reference is invalidated
+    /// while still in use. This is why [`Cell`] requires `T: Copy` for reading too.
+    ///
+    /// [`get`]: `ReadCell::get`
+    ///
+    /// # Safety
+    ///
+    /// The value must not be modified until this method returns.
+    /// This always holds if the `ReadCell` was created with [`from_ref`]
+    /// or if no [`Cell`] aliasing the value is reachable from `T::clone`.
+    ///
+    /// [`from_ref`]: `ReadCell::from_ref`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let name = String::from("ferris");
+    /// let c = ReadCell::from_ref(&name);
+    ///
+    /// // SAFETY: `c` is created from `&String` so it can't be modified.
+    /// assert_eq!(unsafe { c.get_cloned_unchecked() }, "ferris");
+    ///
+    /// let bytes = Cell::new(vec![1u8, 2, 3]);
+    /// let c = ReadCell::from_cell(&bytes);
+    ///
+    /// // SAFETY: `Vec::clone` can't access `bytes`.
+    /// let snapshot = unsafe { c.get_cloned_unchecked() };
+    /// bytes.set(vec![4]);
+    ///
+    /// assert_eq!(snapshot, [1, 2, 3]);
+    /// assert_eq!(unsafe { c.get_cloned_unchecked() }, [4]);
+    /// ```
+    ///
+    /// This is why the method can't be safe. `Clone` implementation below
+    /// replaces the value being cloned, freeing the `String` while it is read.
+    ///
+    /// ```no_run
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// struct Evil(String);
+    ///
+    /// thread_local! {
+    ///     static SHARED: Cell<Evil> = Cell::new(Evil(String::from("shared")));
+    /// }
+    ///
+    /// impl Clone for Evil {
+    ///     fn clone(&self) -> Self {
+    ///         SHARED.with(|shared| shared.set(Evil(String::new())));
+    ///         Evil(self.0.clone()) // Use after free!
+    ///     }
+    /// }
+    ///
+    /// SHARED.with(|shared| {
+    ///     // Undefined behavior: the value is modified while being cloned.
+    ///     let _ = unsafe { ReadCell::from_cell(shared).get_cloned_unchecked() };
+    /// });
+    /// ```
+    #[inline]
+    pub unsafe fn get_cloned_unchecked(&self) -> T {
+        // SAFETY: `ReadCell` is `!Sync` and the caller guarantees
+        // that the value is not modified while being cloned.
+        unsafe { (*self.value.get()).clone() }
+    }
+
+    /// Clones the contained value into `dst`, reusing its resources.
+    ///
+    /// This is equivalent to `dst.clone_from(&value)`, so buffers owned by `dst`
+    /// may be reused instead of allocating new ones.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`get_cloned_unchecked`],
+    /// the value must not be modified until this method returns.
+    ///
+    /// [`get_cloned_unchecked`]: `ReadCell::get_cloned_unchecked`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let status = String::from("online");
+    /// let c = ReadCell::from_ref(&status);
+    ///
+    /// let mut dst = String::with_capacity(64);
+    /// let buffer = dst.as_ptr();
+    ///
+    /// // SAFETY: `c` is created from `&String` so it can't be modified.
+    /// unsafe { c.clone_to_unchecked(&mut dst) };
+    ///
+    /// assert_eq!(dst, "online");
+    /// assert_eq!(dst.capacity(), 64);
+    /// assert_eq!(dst.as_ptr(), buffer);
+    /// ```
+    #[inline]
+    pub unsafe fn clone_to_unchecked(&self, dst: &mut T) {
+        // SAFETY: `ReadCell` is `!Sync` and the caller guarantees
+        // that the value is not modified while being cloned.
+        unsafe { dst.clone_from(&*self.value.get()) }
+    }
+}
+
+impl<T: Copy> ReadCell<Unaligned<T>> {
+    /// Returns a copy of the contained value, which may be unaligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::{ReadCell, Unaligned};
+    ///
+    /// let c = Cell::new(Unaligned(1u64));
+    /// let view = ReadCell::from_cell(&c);
+    ///
+    /// assert_eq!(view.get_unaligned(), 1);
+    /// c.set(Unaligned(2));
+    /// assert_eq!(view.get_unaligned(), 2);
+    /// ```
+    #[inline]
+    pub fn get_unaligned(&self) -> T {
+        // Reading a packed struct by value is an unaligned read.
+        self.get().0
+    }
+
+    /// Returns a `&ReadCell<Unaligned<T>>` from a possibly unaligned pointer.
+    ///
+    /// This is the way to view fields of `#[repr(packed)]` structs.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `T` for lifetime `'a`,
+    /// though it doesn't have to be aligned.
+    /// The value must not be modified during `'a`,
+    /// except through a [`Cell`] or other interior mutability.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr::addr_of;
+    /// use read_cell::ReadCell;
+    ///
+    /// #[repr(C, packed)]
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: u32,
+    ///     crc: u32,
+    /// }
+    ///
+    /// let header = Header { kind: 1, len: 0x0102_0304, crc: 0xAABB_CCDD };
+    ///
+    /// // SAFETY: Pointers to fields are valid for the lifetime of `header`.
+    /// let len = unsafe { ReadCell::from_unaligned_ptr(addr_of!(header.len)) };
+    /// let crc = unsafe { ReadCell::from_unaligned_ptr(addr_of!(header.crc)) };
+    ///
+    /// assert_eq!(len.get_unaligned(), 0x0102_0304);
+    /// assert_eq!(crc.get_unaligned(), 0xAABB_CCDD);
+    /// ```
+    #[inline]
+    pub unsafe fn from_unaligned_ptr<'a>(ptr: *const T) -> &'a ReadCell<Unaligned<T>> {
+        // SAFETY: `ReadCell<Unaligned<T>>` has the same layout as `T`, but alignment of 1.
+        // The caller guarantees that `ptr` is valid for reads.
+        unsafe { &*(ptr as *const ReadCell<Unaligned<T>>) }
+    }
+}
+
+impl<T: Copy> ReadCell<Option<T>> {
+    /// Returns `true` if the contained option is `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(None);
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// assert!(!view.is_some());
+    /// slot.set(Some(1));
+    /// assert!(view.is_some());
+    /// ```
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        self.get().is_some()
+    }
+
+    /// Returns `true` if the contained option is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(Some(1));
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// assert!(!view.is_none());
+    /// slot.set(None);
+    /// assert!(view.is_none());
+    /// ```
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.get().is_none()
+    }
+
+    /// Returns the contained `Some` value or `default`, reading the cell once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(Some(5));
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// assert_eq!(view.get_or(0), 5);
+    /// slot.set(None);
+    /// assert_eq!(view.get_or(0), 0);
+    /// ```
+    #[inline]
+    pub fn get_or(&self, default: T) -> T {
+        self.get().unwrap_or(default)
+    }
+
+    /// Returns the contained `Some` value or computes it with `f`, reading the cell once.
+    ///
+    /// The cell is read before `f` is called and is not read again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(None);
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// let value = view.get_or_else(|| {
+    ///     slot.set(Some(7));
+    ///     1
+    /// });
+    ///
+    /// assert_eq!(value, 1);
+    /// assert_eq!(view.get_or_else(|| 1), 7);
+    /// ```
+    #[inline]
+    pub fn get_or_else(&self, f: impl FnOnce() -> T) -> T {
+        self.get().unwrap_or_else(f)
+    }
+
+    /// Returns the contained `Some` value, reading the cell once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contained option is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(Some(5)).unwrap_get(), 5);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// ReadCell::new(None::<u32>).unwrap_get();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_get(&self) -> T {
+        match self.get() {
+            Some(value) => value,
+            None => panic!("called `ReadCell::unwrap_get()` on a `None` value"),
+        }
+    }
+
+    /// Maps the contained `Some` value with `f`, reading the cell once.
+    ///
+    /// The cell is read before `f` is called,
+    /// so writes made by `f` through an aliasing [`Cell`] don't affect the result.
+    ///
+    /// [`map_get`] maps the whole `Option`, while `map_some` maps only the inner value.
+    ///
+    /// [`map_get`]: `ReadCell::map_get`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(Some(2));
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// let doubled = view.map_some(|x| {
+    ///     slot.set(None);
+    ///     x * 2
+    /// });
+    ///
+    /// assert_eq!(doubled, Some(4));
+    /// assert_eq!(view.map_some(|x| x * 2), None);
+    /// ```
+    #[inline]
+    pub fn map_some<U>(&self, f: impl FnOnce(T) -> U) -> Option<U> {
+        self.get().map(f)
+    }
+}
+
+/// Accessors for results published through a cell, each reading the cell once.
+///
+/// # Examples
+///
+/// Polling a status until the driver reports an error.
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::ReadCell;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum ErrorCode {
+///     Disconnected,
+/// }
+///
+/// let status: Cell<Result<u32, ErrorCode>> = Cell::new(Ok(0));
+/// let view = ReadCell::from_cell(&status);
+///
+/// let mut polls = 0;
+/// let error = loop {
+///     if let Some(error) = view.err_get() {
+///         break error;
+///     }
+///
+///     // Driver makes progress and eventually disconnects.
+///     polls += 1;
+///     status.set(if polls < 3 { Ok(polls) } else { Err(ErrorCode::Disconnected) });
+/// };
+///
+/// assert_eq!(error, ErrorCode::Disconnected);
+/// assert_eq!(polls, 3);
+/// ```
+impl<T: Copy, E: Copy> ReadCell<Result<T, E>> {
+    /// Returns `true` if the contained result is `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert!(ReadCell::new(Ok::<u8, u8>(1)).is_ok());
+    /// assert!(!ReadCell::new(Err::<u8, u8>(1)).is_ok());
+    /// ```
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.get().is_ok()
+    }
+
+    /// Returns `true` if the contained result is `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert!(ReadCell::new(Err::<u8, u8>(1)).is_err());
+    /// assert!(!ReadCell::new(Ok::<u8, u8>(1)).is_err());
+    /// ```
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        self.get().is_err()
+    }
+
+    /// Returns the contained `Ok` value, or `None` if the result is `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(Ok::<u8, i32>(1)).ok_get(), Some(1));
+    /// assert_eq!(ReadCell::new(Err::<u8, i32>(-1)).ok_get(), None);
+    /// ```
+    #[inline]
+    pub fn ok_get(&self) -> Option<T> {
+        self.get().ok()
+    }
+
+    /// Returns the contained `Err` value, or `None` if the result is `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(Err::<u8, i32>(-1)).err_get(), Some(-1));
+    /// assert_eq!(ReadCell::new(Ok::<u8, i32>(1)).err_get(), None);
+    /// ```
+    #[inline]
+    pub fn err_get(&self) -> Option<E> {
+        self.get().err()
+    }
+}
+
+impl<T: Copy, E: Copy + Debug> ReadCell<Result<T, E>> {
+    /// Returns the contained `Ok` value, reading the cell once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contained result is `Err`, with a message including the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(Ok::<u8, i32>(1)).unwrap_get(), 1);
+    /// ```
+    ///
+    /// ```
+    /// use std::panic::catch_unwind;
+    /// use read_cell::ReadCell;
+    ///
+    /// let payload = catch_unwind(|| ReadCell::new(Err::<u8, i32>(-1)).unwrap_get()).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     payload.downcast_ref::<String>().unwrap(),
+    ///     "called `ReadCell::unwrap_get()` on an `Err` value: -1",
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_get(&self) -> T {
+        match self.get() {
+            Ok(value) => value,
+            Err(err) => panic!("called `ReadCell::unwrap_get()` on an `Err` value: {:?}", err),
+        }
+    }
+
+    /// Returns the contained `Ok` value, reading the cell once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contained result is `Err`, with a message including `msg` and the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// assert_eq!(ReadCell::new(Ok::<u8, i32>(1)).expect_get("driver failed"), 1);
+    /// ```
+    ///
+    /// ```
+    /// use std::panic::catch_unwind;
+    /// use read_cell::ReadCell;
+    ///
+    /// let payload = catch_unwind(|| ReadCell::new(Err::<u8, i32>(-1)).expect_get("driver failed")).unwrap_err();
+    ///
+    /// assert_eq!(payload.downcast_ref::<String>().unwrap(), "driver failed: -1");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_get(&self, msg: &str) -> T {
+        match self.get() {
+            Ok(value) => value,
+            Err(err) => panic!("{}: {:?}", msg, err),
+        }
+    }
+}
+
+impl<T> ReadCell<MaybeUninit<T>> {
+    /// Returns a copy of the contained value, assuming it is initialized.
+    ///
+    /// # Safety
+    ///
+    /// The value must be initialized. Writers must not de-initialize it,
+    /// e.g. by storing [`MaybeUninit::uninit`] through an aliasing [`Cell`],
+    /// while it may still be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, mem::MaybeUninit};
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(MaybeUninit::<u32>::uninit());
+    /// let view = ReadCell::from_cell(&slot);
+    ///
+    /// slot.set(MaybeUninit::new(5));
+    ///
+    /// // SAFETY: The value was just initialized.
+    /// assert_eq!(unsafe { view.assume_init_get() }, 5);
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init_get(&self) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: Caller guarantees that the value is initialized.
+        unsafe { self.get().assume_init() }
+    }
+
+    /// Returns a raw pointer to the contained value.
+    ///
+    /// Reading through the pointer is only sound once the value is initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, mem::MaybeUninit};
+    /// use read_cell::ReadCell;
+    ///
+    /// let slot = Cell::new(MaybeUninit::<u32>::uninit());
+    /// let ptr: *const u32 = ReadCell::from_cell(&slot).as_uninit_ptr();
+    ///
+    /// slot.set(MaybeUninit::new(7));
+    /// assert_eq!(unsafe { ptr.read() }, 7);
+    /// ```
+    #[inline]
+    pub const fn as_uninit_ptr(&self) -> *const T {
+        self.as_const_ptr().cast()
+    }
+}
+
+impl<T> ReadCell<[MaybeUninit<T>]> {
+    /// Returns cells of the first `len` elements, assuming they are initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` elements must be initialized.
+    /// Writers must not de-initialize any of them while the returned cells are alive,
+    /// e.g. by storing [`MaybeUninit::uninit`] through an aliasing [`Cell`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, mem::MaybeUninit};
+    /// use read_cell::ReadCell;
+    ///
+    /// let buffer = Cell::new([MaybeUninit::<u16>::uninit(); 8]);
+    /// let filled = Cell::new(0);
+    ///
+    /// let view: &ReadCell<[MaybeUninit<u16>]> = ReadCell::from_cell(&buffer);
+    /// let slots = buffer.as_ptr().cast::<u16>();
+    ///
+    /// // Writer initializes the buffer progressively.
+    /// for i in 0..3 {
+    ///     unsafe { slots.add(i).write(i as u16 * 10) };
+    ///     filled.set(i + 1);
+    /// }
+    ///
+    /// // SAFETY: The writer has initialized `filled` elements and never de-initializes them.
+    /// let ready = unsafe { view.assume_init_slice_of_cells(filled.get()) };
+    /// assert_eq!(ready.len(), 3);
+    /// assert_eq!(ready.iter().map(ReadCell::get).collect::<Vec<_>>(), [0, 10, 20]);
+    ///
+    /// unsafe { slots.add(1).write(11) };
+    /// assert_eq!(ready[1].get(), 11);
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init_slice_of_cells(&self, len: usize) -> &[ReadCell<T>] {
+        let cells = &self.as_slice_of_cells()[..len];
+
+        // SAFETY: `MaybeUninit<T>` has the same memory layout as `T`.
+        // Caller guarantees that the elements are initialized.
+        unsafe { &*(cells as *const [ReadCell<MaybeUninit<T>>] as *const [ReadCell<T>]) }
+    }
+}
+
+impl<T: ?Sized> ReadCell<T> {
+    /// Returns a raw pointer to the underlying data in this cell.
+    ///
+    /// The pointer is `*mut T` for parity with [`Cell::as_ptr`].
+    /// Writing through it is only sound if the value is not borrowed as `&T` anywhere.
+    /// In particular, never write through a pointer of a `ReadCell` created with [`from_ref`].
+    /// Prefer [`as_const_ptr`] unless mutable pointer is required.
+    ///
+    /// [`from_ref`]: `ReadCell::from_ref`
+    /// [`as_const_ptr`]: `ReadCell::as_const_ptr`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    ///
+    /// let ptr = c.as_ptr();
+    /// ```
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+
+    /// Returns a read-only raw pointer to the underlying data in this cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell = Cell::new(5);
+    /// let ptr: *const i32 = ReadCell::from_cell(&cell).as_const_ptr();
+    ///
+    /// cell.set(6);
+    /// assert_eq!(unsafe { ptr.read() }, 6);
+    /// assert_eq!(ptr, cell.as_ptr());
+    /// ```
+    #[inline]
+    pub const fn as_const_ptr(&self) -> *const T {
+        self.value.get()
+    }
+
+    /// Returns the address of the underlying data in this cell.
+    ///
+    /// Same as `self.as_ptr() as *const () as usize`,
+    /// but without exposing pointer provenance.
+    /// For slices and strings only the address of the data is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    /// assert_eq!(c.addr(), c.as_ptr() as usize);
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// let (head, tail) = cell_slice.split_at(1);
+    ///
+    /// assert_eq!(head.addr(), cell_slice.addr());
+    /// assert_eq!(tail.addr(), cell_slice.addr() + size_of::<i32>());
+    /// ```
+    #[inline]
+    pub fn addr(&self) -> usize {
+        self.as_const_ptr().cast::<()>().addr()
+    }
+
+    /// Returns the metadata of the pointer to the underlying data in this cell.
+    ///
+    /// That is the length for slices and strings, and `()` for sized values.
+    /// On stable compiler use [`len`] instead.
+    ///
+    /// Requires `nightly` feature and nightly compiler.
+    ///
+    /// [`len`]: `ReadCell::len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(read_cell_nightly)] {
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// assert_eq!(cell_slice.metadata(), 3);
+    ///
+    /// let text: &ReadCell<str> = ReadCell::from_ref("hello");
+    /// assert_eq!(text.metadata(), 5);
+    ///
+    /// let () = ReadCell::new(5).metadata();
+    /// # }
+    /// ```
+    #[cfg(read_cell_nightly)]
+    #[inline]
+    pub fn metadata(&self) -> <T as core::ptr::Pointee>::Metadata {
+        ptr::metadata(self.as_const_ptr())
+    }
+
+    /// Returns a [`NonNull`] pointer to the underlying data in this cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let c = ReadCell::new(5);
+    /// assert_eq!(c.as_non_null().as_ptr(), c.as_ptr());
+    ///
+    /// let slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// assert_eq!(slice.as_non_null().as_ptr(), slice.as_ptr());
+    /// assert_eq!(slice.as_non_null().len(), 3);
+    ///
+    /// let text: &ReadCell<str> = ReadCell::from_ref("hello");
+    /// assert_eq!(text.as_non_null().as_ptr(), text.as_ptr());
+    ///
+    /// let unit = ReadCell::new(());
+    /// assert_eq!(unit.as_non_null().as_ptr(), unit.as_ptr());
+    /// ```
+    #[inline]
+    pub const fn as_non_null(&self) -> NonNull<T> {
+        // SAFETY: Pointer derived from a reference is never null.
+        unsafe { NonNull::new_unchecked(self.value.get()) }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// This call borrows `ReadCell` mutably (at compile-time) which guarantees
+    /// that we possess the only reference.
+    ///
+    /// However be cautious: this method expects `self` to be mutable, which is
+    /// generally not the case when using a [`ReadCell`]. If you require interior
+    /// mutability by reference, consider using [`RefCell`] which provides
+    /// run-time checked mutable borrows through its [`borrow_mut`] method.
+    ///
+    /// [`RefCell`]: `core::cell::RefCell`
+    /// [`borrow_mut`]: `core::cell::RefCell::borrow_mut`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let mut c = ReadCell::new(5);
+    /// *c.get_mut() += 1;
+    ///
+    /// assert_eq!(c.get(), 6);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Returns a `&ReadCell<T>` from a `&T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &[i32] = &[1, 2, 3];
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(slice);
+    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
+    ///
+    /// assert_eq!(slice_cell.len(), 3);
+    /// ```
+    ///
+    /// It is usable in const contexts. Note that the resulting reference
+    /// can't be the final value of a `const` or `static` item,
+    /// since `ReadCell` contains an `UnsafeCell` and is not `Sync`.
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// const DATA: &[u8] = &[1, 2, 3];
+    /// const SECOND: u8 = ReadCell::from_ref(DATA).as_slice_of_cells()[1].get();
+    ///
+    /// assert_eq!(SECOND, 2);
+    /// ```
+    #[inline]
+    pub const fn from_ref(t: &T) -> &ReadCell<T> {
+        // SAFETY: `&ReadCell<T>` disallows mutations.
+        unsafe { &*(t as *const T as *const ReadCell<T>) }
+    }
+
+    /// Returns a `&ReadCell<T>` from a `&Cell<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(slice);
+    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
+    ///
+    /// assert_eq!(slice_cell.len(), 3);
+    /// ```
+    ///
+    /// It is usable in const contexts.
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// const THIRD: i32 = ReadCell::from_cell(&Cell::new([1, 2, 3])).as_array_of_cells()[2].get();
+    ///
+    /// assert_eq!(THIRD, 3);
+    /// ```
+    #[inline]
+    pub const fn from_cell(t: &Cell<T>) -> &ReadCell<T> {
+        // SAFETY: `&ReadCell<T>` is more restricted than `Cell`.
+        unsafe { &*(t.as_ptr() as *const ReadCell<T>) }
+    }
+
+    /// Returns a pinned `ReadCell<T>` from a pinned `T`.
+    ///
+    /// See [`from_pin_cell`] for pinned [`Cell`]s.
+    /// The value is not moved, so it stays pinned.
+    /// This allows handing out read-only views of structurally pinned fields.
+    /// Pinned reference dereferences to `&ReadCell<T>`, so [`get`] and [`as_ptr`]
+    /// are available on it as usual.
+    ///
+    /// `ReadCell<T>` is [`Unpin`] if and only if `T` is, same as other wrappers.
+    ///
+    /// [`from_pin_cell`]: `ReadCell::from_pin_cell`
+    /// [`get`]: `ReadCell::get`
+    /// [`as_ptr`]: `ReadCell::as_ptr`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, marker::PhantomPinned, pin::{pin, Pin}};
+    /// use pin_project_lite::pin_project;
+    /// use read_cell::ReadCell;
+    ///
+    /// pin_project! {
+    ///     struct Machine {
+    ///         #[pin]
+    ///         step: u32,
+    ///         #[pin]
+    ///         progress: Cell<u8>,
+    ///         #[pin]
+    ///         _pinned: PhantomPinned,
+    ///     }
+    /// }
+    ///
+    /// impl Machine {
+    ///     fn step(self: Pin<&Self>) -> Pin<&ReadCell<u32>> {
+    ///         ReadCell::from_pin_ref(self.project_ref().step)
+    ///     }
+    ///
+    ///     fn progress(self: Pin<&Self>) -> Pin<&ReadCell<u8>> {
+    ///         ReadCell::from_pin_cell(self.project_ref().progress)
+    ///     }
+    /// }
+    ///
+    /// let machine = pin!(Machine { step: 3, progress: Cell::new(0), _pinned: PhantomPinned });
+    /// let machine = machine.into_ref();
+    ///
+    /// let step = machine.step();
+    /// let progress = machine.progress();
+    ///
+    /// assert_eq!(step.get(), 3);
+    /// assert_eq!(progress.get(), 0);
+    /// assert_eq!(step.as_ptr().cast_const(), &machine.step as *const u32);
+    ///
+    /// machine.progress.set(50);
+    /// assert_eq!(progress.get(), 50);
+    /// ```
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// fn is_unpin<T: ?Sized + Unpin>() -> bool { true }
+    ///
+    /// assert!(is_unpin::<ReadCell<u32>>());
+    /// assert!(is_unpin::<ReadCell<[u8]>>());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use std::marker::PhantomPinned;
+    /// use read_cell::ReadCell;
+    ///
+    /// fn is_unpin<T: ?Sized + Unpin>() {}
+    ///
+    /// is_unpin::<ReadCell<PhantomPinned>>();
+    /// ```
+    #[inline]
+    pub const fn from_pin_ref(p: Pin<&T>) -> Pin<&ReadCell<T>> {
+        // SAFETY: The `ReadCell<T>` is the same pinned value, it is never moved.
+        unsafe { Pin::new_unchecked(ReadCell::from_ref(Pin::get_ref(p))) }
+    }
+
+    /// Returns a pinned `ReadCell<T>` from a pinned `Cell<T>`.
+    ///
+    /// Same as [`from_pin_ref`], but the value may be modified through the `Cell`.
+    ///
+    /// [`from_pin_ref`]: `ReadCell::from_pin_ref`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, pin::pin};
+    /// use read_cell::ReadCell;
+    ///
+    /// let counter = pin!(Cell::new(1));
+    /// let counter = counter.into_ref();
+    /// let view = ReadCell::from_pin_cell(counter);
+    ///
+    /// counter.get_ref().set(2);
+    /// assert_eq!(view.get(), 2);
+    /// ```
+    #[inline]
+    pub const fn from_pin_cell(p: Pin<&Cell<T>>) -> Pin<&ReadCell<T>> {
+        // SAFETY: The `ReadCell<T>` is the same pinned value, it is never moved.
+        unsafe { Pin::new_unchecked(ReadCell::from_cell(Pin::get_ref(p))) }
+    }
+
+    /// Returns a `&Cell<T>` from a `&ReadCell<T>`.
+    ///
+    /// This is the inverse of [`from_cell`].
+    ///
+    /// [`from_cell`]: `ReadCell::from_cell`
+    ///
+    /// # Safety
+    ///
+    /// No `&T` references to the same value may exist while the returned `&Cell<T>` is alive.
+    /// In particular, a `ReadCell` obtained with [`from_ref`] must never be turned into a `Cell`.
+    ///
+    /// [`from_ref`]: `ReadCell::from_ref`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell = Cell::new(5);
+    /// let view = ReadCell::from_cell(&cell);
+    ///
+    /// // SAFETY: `view` was obtained from a `Cell`, there are no `&i32` to the value.
+    /// let writer = unsafe { view.as_cell() };
+    /// writer.set(6);
+    ///
+    /// assert!(std::ptr::eq(writer, &cell));
+    /// assert_eq!(view.get(), 6);
+    /// ```
+    ///
+    /// Writing to a value that is borrowed as `&T` is undefined behavior.
+    ///
+    /// ```no_run
+    /// use read_cell::ReadCell;
+    ///
+    /// let value = 5;
+    /// let shared: &i32 = &value;
+    /// let view = ReadCell::from_ref(shared);
+    ///
+    /// // Undefined behavior: `shared` is still alive.
+    /// unsafe { view.as_cell() }.set(6);
+    /// assert_eq!(*shared, 5);
+    /// ```
+    #[inline]
+    pub const unsafe fn as_cell(&self) -> &Cell<T> {
+        // SAFETY: `Cell<T>` has the same memory layout as `ReadCell<T>`.
+        // Caller guarantees that mutation through `Cell` is allowed.
+        unsafe { &*(self as *const ReadCell<T> as *const Cell<T>) }
+    }
+
+    /// Returns a `&T` to the contained value.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be modified through any alias,
+    /// e.g. an aliasing [`Cell`], while the returned reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let buffer = Cell::new([0u8; 1024]);
+    /// let view = ReadCell::from_cell(&buffer);
+    ///
+    /// buffer.set([1; 1024]);
+    ///
+    /// // SAFETY: `buffer` is not modified while `bytes` is alive.
+    /// let bytes: &[u8; 1024] = unsafe { view.as_inner_ref() };
+    /// assert!(bytes.iter().all(|&b| b == 1));
+    /// ```
+    ///
+    /// Modifying the value while the reference is alive is undefined behavior.
+    ///
+    /// ```no_run
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell = Cell::new(5);
+    /// let value: &i32 = unsafe { ReadCell::from_cell(&cell).as_inner_ref() };
+    ///
+    /// // Undefined behavior: `value` is still alive.
+    /// cell.set(6);
+    /// assert_eq!(*value, 5);
+    /// ```
+    #[inline]
+    pub const unsafe fn as_inner_ref(&self) -> &T {
+        // SAFETY: Caller guarantees that the value is not modified
+        // while the reference is alive.
+        unsafe { &*self.value.get() }
+    }
+
+    /// Returns a `&ReadCell<T>` from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, properly aligned and valid for reads of `T` for lifetime `'a`.
+    /// The value may be modified through other pointers during `'a`,
+    /// as long as no `&mut T` to it is created and it is not modified from other threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr::addr_of_mut;
+    /// use read_cell::ReadCell;
+    ///
+    /// static mut STATUS: u32 = 0;
+    ///
+    /// let status = addr_of_mut!(STATUS);
+    ///
+    /// // SAFETY: `STATUS` lives forever and is never borrowed mutably.
+    /// let view = unsafe { ReadCell::from_ptr(status) };
+    /// assert_eq!(view.get(), 0);
+    ///
+    /// unsafe { status.write(1) };
+    /// assert_eq!(view.get(), 1);
+    /// ```
+    #[inline]
+    pub const unsafe fn from_ptr<'a>(ptr: *const T) -> &'a ReadCell<T> {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`.
+        // Caller guarantees validity of the pointer.
+        unsafe { &*(ptr as *const ReadCell<T>) }
+    }
+
+    /// Returns a `&ReadCell<T>` from a [`NonNull`] pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`from_ptr`].
+    ///
+    /// [`from_ptr`]: `ReadCell::from_ptr`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr::NonNull;
+    /// use read_cell::ReadCell;
+    ///
+    /// let data = NonNull::from(Box::leak(Box::new([1, 2, 3])));
+    ///
+    /// // SAFETY: `data` is valid until it is freed below.
+    /// let view: &ReadCell<[i32]> = unsafe { ReadCell::from_non_null(data) };
+    /// assert_eq!(view, &[1, 2, 3]);
+    ///
+    /// unsafe { data.cast::<i32>().write(4) };
+    /// assert_eq!(view, &[4, 2, 3]);
+    ///
+    /// drop(unsafe { Box::from_raw(data.as_ptr()) });
+    /// ```
+    #[inline]
+    pub const unsafe fn from_non_null<'a>(ptr: NonNull<T>) -> &'a ReadCell<T> {
+        // SAFETY: Caller guarantees validity of the pointer.
+        unsafe { ReadCell::from_ptr(ptr.as_ptr()) }
+    }
+
+    /// Returns a `&mut ReadCell<T>` from a `&mut T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &mut [i32] = &mut [1, 2, 3];
+    /// let cell_slice: &mut ReadCell<[i32]> = ReadCell::from_mut(slice);
+    ///
+    /// cell_slice.get_mut()[0] = 4;
+    ///
+    /// let shared: &ReadCell<[i32]> = cell_slice;
+    /// assert_eq!(shared.as_slice_of_cells()[0].get(), 4);
+    /// assert_eq!(slice, [4, 2, 3]);
+    ///
+    /// let mut value = 5;
+    /// let view: &ReadCell<i32> = ReadCell::from_mut(&mut value);
+    /// assert_eq!(view.get(), 5);
+    /// ```
+    ///
+    /// The original reference can't be used while the view is alive.
+    ///
+    /// ```compile_fail
+    /// use read_cell::ReadCell;
+    ///
+    /// let mut value = 5;
+    /// let view: &ReadCell<i32> = ReadCell::from_mut(&mut value);
+    ///
+    /// value = 6;
+    /// assert_eq!(view.get(), 6);
+    /// ```
+    #[inline]
+    pub fn from_mut(t: &mut T) -> &mut ReadCell<T> {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and `&mut` guarantees unique access.
+        unsafe { &mut *(t as *mut T as *mut ReadCell<T>) }
+    }
+
+    /// Returns a `&mut ReadCell<T>` from a `&mut Cell<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let mut cell = Cell::new([1, 2, 3]);
+    /// let cell_array: &mut ReadCell<[i32; 3]> = ReadCell::from_cell_mut(&mut cell);
+    ///
+    /// cell_array.get_mut()[2] = 4;
+    /// assert_eq!(cell.get(), [1, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn from_cell_mut(t: &mut Cell<T>) -> &mut ReadCell<T> {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `Cell<T>`
+        // and `&mut` guarantees unique access.
+        unsafe { &mut *(t as *mut Cell<T> as *mut ReadCell<T>) }
+    }
+
+    /// Returns `true` if both references point to the same storage.
+    ///
+    /// Values are not compared. For slices and strings the lengths must match too,
+    /// so a subslice view is never equal to the whole view.
+    /// Pointers are compared directly, without casting them to integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let a = Cell::new(5);
+    /// let b = Cell::new(5);
+    ///
+    /// assert!(ReadCell::ptr_eq(ReadCell::from_cell(&a), ReadCell::from_cell(&a)));
+    /// assert!(!ReadCell::ptr_eq(ReadCell::from_cell(&a), ReadCell::from_cell(&b)));
+    ///
+    /// let data = [1, 2, 3];
+    /// let whole: &ReadCell<[i32]> = ReadCell::from_ref(&data[..]);
+    /// let head: &ReadCell<[i32]> = ReadCell::from_ref(&data[..2]);
+    ///
+    /// assert!(ReadCell::ptr_eq(whole, ReadCell::from_ref(&data[..])));
+    /// assert!(!ReadCell::ptr_eq(whole, head));
+    /// ```
+    #[inline]
+    pub fn ptr_eq(a: &ReadCell<T>, b: &ReadCell<T>) -> bool {
+        ptr::eq(a, b)
+    }
+
+    /// Returns `true` if this `ReadCell` is a view of the given [`Cell`].
+    ///
+    /// Same as [`ptr_eq`], lengths of slices and strings must match too.
+    ///
+    /// [`ptr_eq`]: `ReadCell::ptr_eq`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let a = Cell::new([1, 2, 3]);
+    /// let b = Cell::new([1, 2, 3]);
+    /// let view = ReadCell::from_cell(&a);
+    ///
+    /// assert!(view.aliases_cell(&a));
+    /// assert!(!view.aliases_cell(&b));
+    ///
+    /// let cells: &Cell<[i32]> = &a;
+    /// let slice_view: &ReadCell<[i32]> = view;
+    ///
+    /// assert!(slice_view.aliases_cell(cells));
+    /// ```
+    #[inline]
+    pub fn aliases_cell(&self, cell: &Cell<T>) -> bool {
+        ptr::eq(self.as_ptr(), cell.as_ptr())
+    }
+}
+
+impl<T> ReadCell<[T]> {
+    /// Returns a `&[ReadCell<T>]` from a `&ReadCell<[T]>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &[i32] = &[1, 2, 3];
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(slice);
+    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
+    ///
+    /// assert_eq!(slice_cell.len(), 3);
+    ///
+    /// const fn first(view: &ReadCell<[i32]>) -> i32 {
+    ///     view.as_slice_of_cells()[0].get()
+    /// }
+    ///
+    /// const FIRST: i32 = first(ReadCell::from_ref(&[4, 5, 6]));
+    /// assert_eq!(FIRST, 4);
+    ///
+    /// let array: &ReadCell<[i32; 3]> = &ReadCell::new([1, 2, 3]);
+    /// let slice: &ReadCell<[i32]> = array;
+    /// assert_eq!(slice.as_slice_of_cells()[2].get(), 3);
+    /// ```
+    #[inline]
+    pub const fn as_slice_of_cells(&self) -> &[ReadCell<T>] {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`.
+        unsafe { &*(self as *const ReadCell<[T]> as *const [ReadCell<T>]) }
+    }
+
+    /// Returns a `&mut [ReadCell<T>]` from a `&mut ReadCell<[T]>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let slice: &mut [i32] = &mut [1, 2, 3];
+    /// let cell_slice: &mut ReadCell<[i32]> = ReadCell::from_mut(slice);
+    ///
+    /// for cell in cell_slice.as_mut_slice_of_cells() {
+    ///     *cell.get_mut() *= 10;
+    /// }
+    ///
+    /// assert_eq!(slice, [10, 20, 30]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice_of_cells(&mut self) -> &mut [ReadCell<T>] {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and `&mut` guarantees unique access.
+        unsafe { &mut *(self as *mut ReadCell<[T]> as *mut [ReadCell<T>]) }
+    }
+
+    /// Returns the number of elements in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// assert_eq!(cell_slice.len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.as_slice_of_cells().len()
+    }
+
+    /// Returns `true` if the slice has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let empty: &ReadCell<[i32]> = ReadCell::from_ref(&[]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    ///
+    /// assert!(empty.is_empty());
+    /// assert!(!cell_slice.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over cells of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+    ///
+    /// let iter = cell_slice.iter();
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.map(ReadCell::get).sum::<i32>(), 6);
+    ///
+    /// cells.as_slice_of_cells()[2].set(4);
+    /// let reversed: Vec<i32> = cell_slice.iter().rev().map(ReadCell::get).collect();
+    /// assert_eq!(reversed, [4, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, ReadCell<T>> {
+        self.as_slice_of_cells().iter()
+    }
+
+    /// Returns a cell of the first element, or `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// let empty: &ReadCell<[i32]> = ReadCell::from_ref(&[]);
+    ///
+    /// assert_eq!(cell_slice.first().map(ReadCell::get), Some(1));
+    /// assert!(empty.first().is_none());
+    /// ```
+    #[inline]
+    pub const fn first(&self) -> Option<&ReadCell<T>> {
+        self.as_slice_of_cells().first()
+    }
+
+    /// Returns a cell of the last element, or `None` if the slice is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use read_cell::ReadCell;
     ///
-    /// let slice: &[i32] = &[1, 2, 3];
-    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(slice);
-    /// let slice_cell: &[ReadCell<i32>] = cell_slice.as_slice_of_cells();
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// let empty: &ReadCell<[i32]> = ReadCell::from_ref(&[]);
     ///
-    /// assert_eq!(slice_cell.len(), 3);
+    /// assert_eq!(cell_slice.last().map(ReadCell::get), Some(3));
+    /// assert!(empty.last().is_none());
+    /// ```
+    #[inline]
+    pub const fn last(&self) -> Option<&ReadCell<T>> {
+        self.as_slice_of_cells().last()
+    }
+
+    /// Returns a cell of the first element and the rest of the slice,
+    /// or `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+    ///
+    /// let (first, rest) = cell_slice.split_first().unwrap();
+    /// assert_eq!(first.get(), 1);
+    /// assert_eq!(rest, &[2, 3]);
+    ///
+    /// cells.as_slice_of_cells()[0].set(4);
+    /// assert_eq!(first.get(), 4);
+    ///
+    /// let empty: &ReadCell<[i32]> = ReadCell::from_ref(&[]);
+    /// assert!(empty.split_first().is_none());
+    /// ```
+    #[inline]
+    pub const fn split_first(&self) -> Option<(&ReadCell<T>, &ReadCell<[T]>)> {
+        match self.as_slice_of_cells().split_first() {
+            Some((first, rest)) => Some((first, ReadCell::from_slice_of_cells(rest))),
+            None => None,
+        }
+    }
+
+    /// Returns a cell of the last element and the rest of the slice,
+    /// or `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+    ///
+    /// let (last, rest) = cell_slice.split_last().unwrap();
+    /// assert_eq!(last.get(), 3);
+    /// assert_eq!(rest, &[1, 2]);
+    ///
+    /// cells.as_slice_of_cells()[2].set(4);
+    /// assert_eq!(last.get(), 4);
+    ///
+    /// let empty: &ReadCell<[i32]> = ReadCell::from_ref(&[]);
+    /// assert!(empty.split_last().is_none());
+    /// ```
+    #[inline]
+    pub const fn split_last(&self) -> Option<(&ReadCell<T>, &ReadCell<[T]>)> {
+        match self.as_slice_of_cells().split_last() {
+            Some((last, rest)) => Some((last, ReadCell::from_slice_of_cells(rest))),
+            None => None,
+        }
+    }
+
+    /// Divides the slice into two at `mid`.
+    ///
+    /// The first half contains elements `[0, mid)` and the second `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    ///
+    /// let (head, tail) = cell_slice.split_at(1);
+    /// assert_eq!(head, &[1]);
+    /// assert_eq!(tail, &[2, 3]);
+    ///
+    /// let (head, tail) = cell_slice.split_at(0);
+    /// assert!(head.is_empty());
+    /// assert_eq!(tail, &[1, 2, 3]);
+    ///
+    /// let (head, tail) = cell_slice.split_at(3);
+    /// assert_eq!(head, &[1, 2, 3]);
+    /// assert!(tail.is_empty());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// cell_slice.split_at(4);
+    /// ```
+    #[inline]
+    pub const fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        let (head, tail) = self.as_slice_of_cells().split_at(mid);
+        (
+            ReadCell::from_slice_of_cells(head),
+            ReadCell::from_slice_of_cells(tail),
+        )
+    }
+
+    /// Divides the slice into two at `mid`, returning `None` if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    ///
+    /// let (head, tail) = cell_slice.split_at_checked(3).unwrap();
+    /// assert_eq!(head, &[1, 2, 3]);
+    /// assert!(tail.is_empty());
+    ///
+    /// assert!(cell_slice.split_at_checked(4).is_none());
+    /// ```
+    #[inline]
+    pub const fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+        match self.as_slice_of_cells().split_at_checked(mid) {
+            Some((head, tail)) => Some((
+                ReadCell::from_slice_of_cells(head),
+                ReadCell::from_slice_of_cells(tail),
+            )),
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time.
+    ///
+    /// The last chunk is shorter if `size` does not divide the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+    ///
+    /// let mut chunks = cell_slice.chunks(3);
+    /// assert_eq!(chunks.len(), 3);
+    ///
+    /// let first = chunks.next().unwrap();
+    /// assert_eq!(first, &[1, 2, 3]);
+    /// assert_eq!(chunks.next().unwrap(), &[4, 5, 6]);
+    /// assert_eq!(chunks.next().unwrap(), &[7]);
+    /// assert!(chunks.next().is_none());
+    ///
+    /// cells.as_slice_of_cells()[0].set(8);
+    /// assert_eq!(first, &[8, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        Chunks::new(self.as_slice_of_cells(), size)
+    }
+
+    /// Returns an iterator over `size` elements of the slice at a time.
+    ///
+    /// If `size` does not divide the length of the slice, last elements
+    /// are omitted and can be retrieved from [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// let mut chunks = cell_slice.chunks_exact(3);
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks.next().unwrap(), &[1, 2, 3]);
+    /// assert_eq!(chunks.next().unwrap(), &[4, 5, 6]);
+    /// assert!(chunks.next().is_none());
+    /// assert_eq!(chunks.remainder(), &[7]);
+    /// ```
+    #[inline]
+    pub fn chunks_exact(&self, size: usize) -> ChunksExact<'_, T> {
+        ChunksExact::new(self.as_slice_of_cells(), size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`.
+    ///
+    /// The windows overlap. If the slice is shorter than `size`, the iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let cells: &Cell<[i32]> = &Cell::new([1, 2, 3, 4, 5]);
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_cell(cells);
+    ///
+    /// let sums: Vec<i32> = cell_slice
+    ///     .windows(3)
+    ///     .map(|w| w.iter().map(ReadCell::get).sum())
+    ///     .collect();
+    /// assert_eq!(sums, [6, 9, 12]);
+    ///
+    /// let last = cell_slice.windows(2).last().unwrap();
+    /// cells.as_slice_of_cells()[4].set(6);
+    /// assert_eq!(last, &[4, 6]);
+    ///
+    /// assert_eq!(cell_slice.windows(5).len(), 1);
+    /// assert_eq!(cell_slice.windows(6).len(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// let cell_slice: &ReadCell<[i32]> = ReadCell::from_ref(&[1, 2, 3]);
+    /// cell_slice.windows(0);
     /// ```
-    pub fn as_slice_of_cells(&self) -> &[ReadCell<T>] {
+    #[inline]
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        Windows::new(self.as_slice_of_cells(), size)
+    }
+
+    /// Inverse of [`as_slice_of_cells`](`ReadCell::as_slice_of_cells`).
+    #[inline]
+    const fn from_slice_of_cells(cells: &[ReadCell<T>]) -> &ReadCell<[T]> {
         // SAFETY: `ReadCell<T>` has the same memory layout as `T`.
-        unsafe { &*(self as *const ReadCell<[T]> as *const [ReadCell<T>]) }
+        unsafe { &*(cells as *const [ReadCell<T>] as *const ReadCell<[T]>) }
+    }
+}
+
+impl<T: Copy> ReadCell<[T]> {
+    /// Copies all elements into `dst`.
+    ///
+    /// Elements are read one by one at the time of the call,
+    /// so the copy reflects all writes made through an aliasing [`Cell`] before it.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let buffer = Cell::new([0u8; 4]);
+    /// let shared: &ReadCell<[u8]> = ReadCell::from_cell(&buffer);
+    ///
+    /// buffer.set([1, 2, 3, 4]);
+    ///
+    /// let mut snapshot = [0; 4];
+    /// shared.copy_to_slice(&mut snapshot);
+    /// buffer.set([5, 6, 7, 8]);
+    ///
+    /// assert_eq!(snapshot, [1, 2, 3, 4]);
+    ///
+    /// shared[1..3].copy_to_slice(&mut snapshot[..2]);
+    /// assert_eq!(snapshot, [6, 7, 3, 4]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// let shared: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2, 3]);
+    ///
+    /// // Panics: source slice length (3) does not match destination slice length (4)
+    /// shared.copy_to_slice(&mut [0; 4]);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T]) {
+        let src = self.as_slice_of_cells();
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source slice length ({}) does not match destination slice length ({})",
+            src.len(),
+            dst.len(),
+        );
+
+        for (dst, src) in dst.iter_mut().zip(src) {
+            *dst = src.get();
+        }
+    }
+
+    /// Copies all elements into `dst` using volatile reads.
+    ///
+    /// Each element is read with [`ReadCell::get_volatile`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let buffer = Cell::new([0u8; 4]);
+    /// let rx: &ReadCell<[u8]> = ReadCell::from_cell(&buffer);
+    ///
+    /// buffer.set([1, 2, 3, 4]);
+    ///
+    /// let mut dst = [0; 4];
+    /// rx.copy_to_slice_volatile(&mut dst);
+    /// assert_eq!(dst, [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use read_cell::ReadCell;
+    ///
+    /// let rx: &ReadCell<[u8]> = ReadCell::from_ref(&[1, 2, 3]);
+    ///
+    /// // Panics: source slice length (3) does not match destination slice length (2)
+    /// rx.copy_to_slice_volatile(&mut [0; 2]);
+    /// ```
+    pub fn copy_to_slice_volatile(&self, dst: &mut [T]) {
+        let src = self.as_slice_of_cells();
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source slice length ({}) does not match destination slice length ({})",
+            src.len(),
+            dst.len(),
+        );
+
+        for (dst, src) in dst.iter_mut().zip(src) {
+            *dst = src.get_volatile();
+        }
+    }
+}
+
+impl ReadCell<str> {
+    /// Returns a `&ReadCell<[u8]>` from a `&ReadCell<str>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let text: &ReadCell<str> = ReadCell::from_ref("hello");
+    /// let bytes: &ReadCell<[u8]> = text.as_bytes();
+    ///
+    /// assert_eq!(bytes.as_slice_of_cells()[1].get(), b'e');
+    ///
+    /// const LEN: usize = ReadCell::from_ref("hello").as_bytes().as_slice_of_cells().len();
+    /// assert_eq!(LEN, 5);
+    /// ```
+    #[inline]
+    pub const fn as_bytes(&self) -> &ReadCell<[u8]> {
+        // SAFETY: `str` has the same memory layout as `[u8]`
+        // and `&ReadCell<[u8]>` disallows mutations.
+        unsafe { &*(self as *const ReadCell<str> as *const ReadCell<[u8]>) }
+    }
+
+    /// Copies the string out in small chunks and feeds them to `f`.
+    ///
+    /// Invalid UTF-8 sequences, which may only be observed if the string
+    /// is modified through an alias, are replaced with `U+FFFD`.
+    fn for_each_chunk(&self, mut f: impl FnMut(&str) -> fmt::Result) -> fmt::Result {
+        let mut cells = self.as_bytes().as_slice_of_cells().iter();
+        let mut buf = [0u8; 64];
+        let mut carry = 0;
+
+        loop {
+            let mut len = carry;
+            for (dst, src) in buf[carry..].iter_mut().zip(&mut cells) {
+                *dst = src.get();
+                len += 1;
+            }
+
+            if len == 0 {
+                return Ok(());
+            }
+
+            let exhausted = len == carry;
+
+            match str::from_utf8(&buf[..len]) {
+                Ok(chunk) => {
+                    f(chunk)?;
+                    carry = 0;
+                }
+                Err(err) => {
+                    let valid = err.valid_up_to();
+
+                    // SAFETY: Bytes up to `valid` were just validated.
+                    f(unsafe { str::from_utf8_unchecked(&buf[..valid]) })?;
+
+                    let rest = match err.error_len() {
+                        Some(error_len) => {
+                            f("\u{FFFD}")?;
+                            valid + error_len
+                        }
+                        None if exhausted => {
+                            f("\u{FFFD}")?;
+                            len
+                        }
+                        None => valid,
+                    };
+
+                    buf.copy_within(rest..len, 0);
+                    carry = len - rest;
+                }
+            }
+        }
     }
 }
 
+macro_rules! impl_project_tuple {
+    ($(($($name:ident . $idx:tt),*) => $example:literal;)*) => {$(
+        impl<$($name),*> ReadCell<($($name,)*)> {
+            /// Returns cells of the tuple fields.
+            ///
+            /// Each cell points into the tuple storage,
+            /// so it observes writes made to the whole tuple through an aliasing [`Cell`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cell::Cell;
+            /// use read_cell::ReadCell;
+            ///
+            #[doc = $example]
+            /// ```
+            #[inline]
+            pub fn project(&self) -> ($(&ReadCell<$name>,)*) {
+                let ptr = self.as_const_ptr();
+
+                // SAFETY: Fields are within the tuple referenced by `self`
+                // and `ReadCell<T>` has the same memory layout as `T`.
+                unsafe { ($(ReadCell::from_ptr(ptr::addr_of!((*ptr).$idx)),)*) }
+            }
+        }
+    )*};
+}
+
+impl_project_tuple! {
+    (A.0, B.1) => "let pair = Cell::new((1u8, 2u32));
+let (tag, value) = ReadCell::from_cell(&pair).project();
+
+assert_eq!((tag.get(), value.get()), (1, 2));
+
+pair.set((3, 4));
+assert_eq!((tag.get(), value.get()), (3, 4));";
+    (A.0, B.1, C.2) => "let triple = Cell::new((1u8, 2u16, 3u64));
+let (a, b, c) = ReadCell::from_cell(&triple).project();
+
+triple.set((4, 5, 6));
+assert_eq!((a.get(), b.get(), c.get()), (4, 5, 6));";
+    (A.0, B.1, C.2, D.3) => "let quad = Cell::new((1u8, 'b', 3.0f32, [4u16; 2]));
+let (a, b, c, d) = ReadCell::from_cell(&quad).project();
+
+assert_eq!((a.get(), b.get(), c.get(), d.get()), (1, 'b', 3.0, [4, 4]));
+
+quad.set((5, 'f', 7.0, [8, 9]));
+assert_eq!((a.get(), b.get(), c.get(), d.get()), (5, 'f', 7.0, [8, 9]));";
+}
+
 impl<T, const N: usize> ReadCell<[T; N]> {
     /// Returns a `&[ReadCell<T>; N]` from a `&ReadCell<[T; N]>`
     ///
@@ -293,9 +4887,120 @@ impl<T, const N: usize> ReadCell<[T; N]> {
     /// let mut array: [i32; 3] = [1, 2, 3];
     /// let cell_array: &ReadCell<[i32; 3]> = ReadCell::from_ref(&array);
     /// let array_cell: &[ReadCell<i32>; 3] = cell_array.as_array_of_cells();
+    ///
+    /// const LAST: i32 = ReadCell::from_ref(&[1, 2, 3]).as_array_of_cells()[2].get();
+    /// assert_eq!(LAST, 3);
     /// ```
-    pub fn as_array_of_cells(&self) -> &[ReadCell<T>; N] {
+    #[inline]
+    pub const fn as_array_of_cells(&self) -> &[ReadCell<T>; N] {
         // SAFETY: `ReadCell<T>` has the same memory layout as `T`.
         unsafe { &*(self as *const ReadCell<[T; N]> as *const [ReadCell<T>; N]) }
     }
+
+    /// Returns a `&mut [ReadCell<T>; N]` from a `&mut ReadCell<[T; N]>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::ReadCell;
+    ///
+    /// let mut cell_array = ReadCell::new([0; 3]);
+    ///
+    /// for (i, cell) in cell_array.as_mut_array_of_cells().iter_mut().enumerate() {
+    ///     *cell.get_mut() = i;
+    /// }
+    ///
+    /// assert_eq!(cell_array.get(), [0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn as_mut_array_of_cells(&mut self) -> &mut [ReadCell<T>; N] {
+        // SAFETY: `ReadCell<T>` has the same memory layout as `T`
+        // and `&mut` guarantees unique access.
+        unsafe { &mut *(self as *mut ReadCell<[T; N]> as *mut [ReadCell<T>; N]) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::ReadCell;
+
+    #[test]
+    fn as_inner_ref_between_cell_writes() {
+        let cell = Cell::new([1u8; 16]);
+        let view = ReadCell::from_cell(&cell);
+
+        // SAFETY: `cell` is not modified while `bytes` is alive.
+        let bytes = unsafe { view.as_inner_ref() };
+        assert!(bytes.iter().all(|&b| b == 1));
+
+        // `bytes` is dead, so writing through the aliasing `Cell` is allowed again.
+        cell.set([2; 16]);
+        assert_eq!(view.get(), [2; 16]);
+
+        // SAFETY: `cell` is not modified while the reference is alive.
+        assert!(unsafe { view.as_inner_ref() }.iter().all(|&b| b == 2));
+    }
+
+    #[test]
+    fn as_inner_ref_of_slice_view() {
+        let cell: &Cell<[u32]> = &Cell::new([1, 2, 3, 4]);
+        let view = ReadCell::from_cell(cell);
+
+        // SAFETY: `cell` is not modified while `values` is alive.
+        let values = unsafe { view.as_inner_ref() };
+        assert_eq!(values.iter().sum::<u32>(), 10);
+
+        cell.as_slice_of_cells()[3].set(5);
+
+        // SAFETY: `cell` is not modified while the reference is alive.
+        assert_eq!(unsafe { view.as_inner_ref() }, &[1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn as_inner_ref_alongside_shared_ref() {
+        let value = [7u64; 4];
+        let view = ReadCell::from_ref(&value);
+
+        // SAFETY: Nothing may modify `value` while it is borrowed.
+        let inner = unsafe { view.as_inner_ref() };
+        assert_eq!(inner, &value);
+        assert_eq!(value[0], inner[0]);
+    }
+
+    #[test]
+    fn as_const_ptr_reads_across_cell_writes() {
+        let cell = Cell::new(1u32);
+        let ptr = ReadCell::from_cell(&cell).as_const_ptr();
+
+        for i in 2..10 {
+            // Raw pointers don't assert immutability, so they may outlive writes.
+            cell.set(i);
+
+            // SAFETY: `ptr` points to `cell`, which is alive.
+            assert_eq!(unsafe { ptr.read() }, i);
+        }
+    }
+
+    #[test]
+    fn as_const_ptr_of_shared_ref() {
+        let value = [3i16; 8];
+        let view: &ReadCell<[i16]> = ReadCell::from_ref(&value);
+        let ptr = view.as_const_ptr();
+
+        // SAFETY: `ptr` points to `value`, which is alive and not modified.
+        let copy = unsafe { ptr.cast::<[i16; 8]>().read() };
+        assert_eq!(copy, value);
+        assert_eq!(view.len(), value.len());
+    }
+
+    #[test]
+    fn as_const_ptr_matches_as_ptr() {
+        let cell = Cell::new(5u8);
+        let view = ReadCell::from_cell(&cell);
+
+        assert_eq!(view.as_const_ptr(), view.as_ptr().cast_const());
+        assert_eq!(view.as_const_ptr(), cell.as_ptr().cast_const());
+    }
 }
@@ -0,0 +1,122 @@
+use crate::ReadCell;
+
+/// Returns a `&ReadCell<U>` viewing a field of a struct.
+///
+/// Given `&ReadCell<Struct>`, the macro yields `&ReadCell<FieldType>`
+/// pointing at the named field, so it observes writes made to the struct
+/// through an aliasing [`Cell`].
+///
+/// The struct type is named explicitly, which guarantees that the field
+/// belongs to the struct itself and is not reached through [`Deref`].
+/// Field offset is taken with [`offset_of!`], so `#[repr(C)]` is not required.
+/// Fields of `#[repr(packed)]` structs are rejected, since they may be unaligned.
+/// View them with [`ReadCell::from_unaligned_ptr`] instead.
+/// Union fields are rejected too, since reading them may produce an invalid value.
+///
+/// [`Cell`]: `core::cell::Cell`
+/// [`Deref`]: `core::ops::Deref`
+/// [`offset_of!`]: `core::mem::offset_of`
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use read_cell::{read_cell_project, ReadCell};
+///
+/// #[derive(Clone, Copy)]
+/// struct Telemetry {
+///     seq: u32,
+///     temperature: f32,
+///     flags: u8,
+/// }
+///
+/// let shared = Cell::new(Telemetry { seq: 1, temperature: 20.5, flags: 0 });
+/// let view = ReadCell::from_cell(&shared);
+///
+/// let seq: &ReadCell<u32> = read_cell_project!(view, Telemetry, seq);
+/// let temperature: &ReadCell<f32> = read_cell_project!(view, Telemetry, temperature);
+///
+/// assert_eq!(seq.get(), 1);
+/// assert_eq!(temperature.get(), 20.5);
+///
+/// shared.set(Telemetry { seq: 2, temperature: 21.0, flags: 1 });
+///
+/// assert_eq!(seq.get(), 2);
+/// assert_eq!(temperature.get(), 21.0);
+/// ```
+///
+/// Fields reached through `Deref` are rejected.
+///
+/// ```compile_fail
+/// use read_cell::{read_cell_project, ReadCell};
+///
+/// struct Inner {
+///     value: u32,
+/// }
+///
+/// let boxed = ReadCell::new(Box::new(Inner { value: 1 }));
+/// let _ = read_cell_project!(&boxed, Box<Inner>, value);
+/// ```
+///
+/// Fields of packed structs are rejected.
+///
+/// ```compile_fail
+/// use read_cell::{read_cell_project, ReadCell};
+///
+/// #[repr(C, packed)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+/// }
+///
+/// let header = ReadCell::new(Header { kind: 1, len: 2 });
+/// let _ = read_cell_project!(&header, Header, len);
+/// ```
+///
+/// Union fields are rejected.
+///
+/// ```compile_fail
+/// #![forbid(unsafe_code)]
+/// use read_cell::{read_cell_project, ReadCell};
+///
+/// union Bits {
+///     raw: u8,
+///     flag: bool,
+/// }
+///
+/// let bits = ReadCell::new(Bits { raw: 3 });
+/// let _ = read_cell_project!(&bits, Bits, flag).get();
+/// ```
+#[macro_export]
+macro_rules! read_cell_project {
+    ($cell:expr, $ty:ty, $field:tt) => {{
+        let cell: &$crate::ReadCell<$ty> = $cell;
+
+        // Built outside of `unsafe` block, so that it rejects union fields,
+        // as well as fields of packed structs.
+        let field: fn(&$ty) -> &_ = |value: &$ty| &value.$field;
+        let offset = ::core::mem::offset_of!($ty, $field);
+
+        // SAFETY: Offset is taken for the field `field` refers to.
+        unsafe { $crate::__project_field(cell, offset, field) }
+    }};
+}
+
+/// Implementation detail of [`read_cell_project!`].
+///
+/// # Safety
+///
+/// `offset` must be the offset of the field returned by `field`.
+#[doc(hidden)]
+#[inline]
+pub unsafe fn project_field<T, U>(
+    cell: &ReadCell<T>,
+    offset: usize,
+    field: fn(&T) -> &U,
+) -> &ReadCell<U> {
+    let _ = field;
+
+    // SAFETY: Caller guarantees that the field is at `offset`, so it is within `T`
+    // and properly aligned, and `ReadCell<U>` has the same memory layout as `U`.
+    unsafe { ReadCell::from_ptr(cell.as_const_ptr().byte_add(offset).cast::<U>()) }
+}
@@ -0,0 +1,65 @@
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    strategy::{Map, Strategy},
+};
+
+use crate::ReadCell;
+
+/// Generates `ReadCell<T>` from an arbitrary `T`, shrinking the same way as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use read_cell::ReadCell;
+///
+/// proptest!(|(c in any::<ReadCell<i32>>())| {
+///     let value = c.get();
+///     prop_assert_eq!(ReadCell::new(value).get(), value);
+/// });
+/// ```
+impl<T> Arbitrary for ReadCell<T>
+where
+    T: Arbitrary + Copy,
+{
+    type Parameters = T::Parameters;
+    type Strategy = Map<T::Strategy, fn(T) -> ReadCell<T>>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<T>(args).prop_map(ReadCell::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{
+        prelude::*,
+        test_runner::{TestError, TestRunner},
+    };
+
+    use crate::ReadCell;
+
+    proptest! {
+        #[test]
+        fn get_roundtrips(c in any::<ReadCell<i32>>()) {
+            let value = c.get();
+            prop_assert_eq!(ReadCell::new(value).get(), value);
+            prop_assert_eq!(c.into_inner(), value);
+        }
+    }
+
+    #[test]
+    fn shrinks_to_boundary() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<ReadCell<i32>>(), |c| {
+            prop_assert!(c.get() < 100);
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, c)) => assert_eq!(c.get(), 100),
+            _ => panic!("property must fail"),
+        }
+    }
+}
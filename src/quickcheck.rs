@@ -0,0 +1,52 @@
+use alloc::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::ReadCell;
+
+/// Generates `ReadCell<T>` from an arbitrary `T`, shrinking the copied value.
+///
+/// # Examples
+///
+/// ```
+/// use quickcheck::quickcheck;
+/// use read_cell::ReadCell;
+///
+/// fn copies_out(c: ReadCell<u32>) -> bool {
+///     c.get() == c.into_inner()
+/// }
+///
+/// quickcheck(copies_out as fn(ReadCell<u32>) -> bool);
+/// ```
+impl<T> Arbitrary for ReadCell<T>
+where
+    T: Arbitrary + Copy,
+{
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        ReadCell::new(T::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().map(ReadCell::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::Arbitrary;
+    use quickcheck_macros::quickcheck;
+
+    use crate::ReadCell;
+
+    #[quickcheck]
+    fn get_roundtrips(x: u64) -> bool {
+        ReadCell::new(x).get() == x
+    }
+
+    #[quickcheck]
+    fn shrinks_like_inner(c: ReadCell<u64>) -> bool {
+        c.shrink().map(|c| c.get()).eq(c.get().shrink())
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use crate::ReadCell;
+
+impl<T: ?Sized> ReadCell<T> {
+    /// Converts `Rc<Cell<T>>` into `Rc<ReadCell<T>>`, reusing the allocation.
+    ///
+    /// Other `Rc<Cell<T>>` handles to the same allocation keep working,
+    /// so a writer may hold on to one and hand out read-only handles to observers.
+    ///
+    /// There's no borrowing counterpart that turns `&Rc<Cell<T>>` into `&Rc<ReadCell<T>>`,
+    /// since `Rc<Cell<T>>` and `Rc<ReadCell<T>>` are not guaranteed to have the same layout.
+    /// Use [`from_cell`] to borrow the value instead.
+    ///
+    /// Available with `alloc` feature.
+    ///
+    /// [`from_cell`]: `ReadCell::from_cell`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, rc::Rc};
+    /// use read_cell::ReadCell;
+    ///
+    /// let writer = Rc::new(Cell::new(5));
+    /// let reader: Rc<ReadCell<i32>> = ReadCell::from_rc(writer.clone());
+    ///
+    /// assert_eq!(reader.get(), 5);
+    ///
+    /// writer.set(6);
+    /// assert_eq!(reader.get(), 6);
+    /// assert_eq!(Rc::strong_count(&reader), 2);
+    ///
+    /// let cells: Rc<Cell<[u8]>> = Rc::new(Cell::new([1, 2, 3]));
+    /// let view: Rc<ReadCell<[u8]>> = ReadCell::from_rc(cells.clone());
+    ///
+    /// cells.as_slice_of_cells()[0].set(4);
+    /// assert_eq!(*view, [4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_rc(rc: Rc<Cell<T>>) -> Rc<ReadCell<T>> {
+        let ptr = Rc::into_raw(rc) as *const ReadCell<T>;
+
+        // SAFETY: `ReadCell<T>` has the same memory layout as `Cell<T>`
+        // and is more restricted.
+        unsafe { Rc::from_raw(ptr) }
+    }
+}
@@ -0,0 +1,115 @@
+use core::ops::Deref;
+
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Trace},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::ReadCell;
+
+/// An archived [`ReadCell`].
+///
+/// Archived data is immutable, so unlike [`ReadCell`] it
+/// hands out plain references to the archived value.
+/// It has the same layout as `T`, which is `T::Archived` of the original value.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::{ArchivedReadCell, ReadCell};
+/// use rkyv::{rancor::Error, Archived};
+///
+/// let c = ReadCell::new(0xDEAD_BEEFu32);
+///
+/// let bytes = rkyv::to_bytes::<Error>(&c).unwrap();
+/// assert_eq!(&bytes[..], &rkyv::to_bytes::<Error>(&0xDEAD_BEEFu32).unwrap()[..]);
+///
+/// let archived: &ArchivedReadCell<Archived<u32>> =
+///     rkyv::access::<Archived<ReadCell<u32>>, Error>(&bytes).unwrap();
+/// assert_eq!(*archived.get(), 0xDEAD_BEEF);
+///
+/// let value = rkyv::from_bytes::<ReadCell<u32>, Error>(&bytes).unwrap();
+/// assert_eq!(value.get(), 0xDEAD_BEEF);
+///
+/// assert_eq!(
+///     core::mem::align_of::<Archived<ReadCell<u64>>>(),
+///     core::mem::align_of::<Archived<u64>>(),
+/// );
+/// ```
+#[repr(transparent)]
+pub struct ArchivedReadCell<T> {
+    value: T,
+}
+
+impl<T> ArchivedReadCell<T> {
+    /// Returns a reference to the archived value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Deref for ArchivedReadCell<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+// SAFETY: `ArchivedReadCell<T>` is `#[repr(transparent)]` over `T`
+// and has no interior mutability of its own.
+unsafe impl<T: Portable> Portable for ArchivedReadCell<T> {}
+
+// SAFETY: `ArchivedReadCell<T>` is `#[repr(transparent)]` over `T`,
+// so it is valid whenever `T` is.
+unsafe impl<T, C> CheckBytes<C> for ArchivedReadCell<T>
+where
+    T: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Trace,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        // SAFETY: The caller guarantees that `value` is aligned and points to
+        // enough bytes for `Self`, which has the same layout as `T`.
+        unsafe { T::check_bytes(value.cast::<T>(), context) }
+    }
+}
+
+impl<T: Archive + Copy> Archive for ReadCell<T> {
+    type Archived = ArchivedReadCell<T::Archived>;
+    type Resolver = T::Resolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedReadCell<T::Archived>` is `#[repr(transparent)]` over `T::Archived`.
+        let out = unsafe { out.cast_unchecked::<T::Archived>() };
+        self.get().resolve(resolver, out)
+    }
+}
+
+impl<T, S> Serialize<S> for ReadCell<T>
+where
+    T: Serialize<S> + Copy,
+    S: Fallible + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<T, D> Deserialize<ReadCell<T>, D> for ArchivedReadCell<T::Archived>
+where
+    T: Archive + Copy,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<ReadCell<T>, D::Error> {
+        self.value.deserialize(deserializer).map(ReadCell::new)
+    }
+}
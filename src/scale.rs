@@ -0,0 +1,75 @@
+use scale::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+
+use crate::ReadCell;
+
+/// Encodes `ReadCell<T>` into exactly the same bytes as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+/// use scale::{Encode, MaxEncodedLen};
+///
+/// let value = (42u32, [1u8, 2], Some(7u64));
+/// let c = ReadCell::new(value);
+///
+/// assert_eq!(c.encode(), value.encode());
+/// assert_eq!(c.size_hint(), value.size_hint());
+/// assert_eq!(ReadCell::<u64>::max_encoded_len(), u64::max_encoded_len());
+/// ```
+impl<T> Encode for ReadCell<T>
+where
+    T: Encode + Copy,
+{
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.get().size_hint()
+    }
+
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.get().encode_to(dest)
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        self.get().encoded_size()
+    }
+}
+
+impl<T> EncodeLike for ReadCell<T> where T: Encode + Copy {}
+
+impl<T> EncodeLike<T> for ReadCell<T> where T: Encode + Copy {}
+
+/// Decodes `ReadCell<T>` from the same bytes as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+/// use scale::{Decode, Encode};
+///
+/// let bytes = 0xC0FFEEu32.encode();
+/// let c = ReadCell::<u32>::decode(&mut &bytes[..]).unwrap();
+///
+/// assert_eq!(c.get(), 0xC0FFEE);
+/// ```
+impl<T> Decode for ReadCell<T>
+where
+    T: Decode,
+{
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        T::decode(input).map(ReadCell::new)
+    }
+}
+
+impl<T> MaxEncodedLen for ReadCell<T>
+where
+    T: MaxEncodedLen + Copy,
+{
+    #[inline]
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len()
+    }
+}
@@ -0,0 +1,48 @@
+use alloc::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::ReadCell;
+
+/// Describes `ReadCell<T>` with exactly the same schema as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+/// use schemars::{schema_for, JsonSchema};
+///
+/// #[derive(JsonSchema)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(schema_for!(ReadCell<u32>), schema_for!(u32));
+/// assert_eq!(schema_for!(ReadCell<Option<bool>>), schema_for!(Option<bool>));
+/// assert_eq!(ReadCell::<Point>::schema_name(), Point::schema_name());
+/// ```
+impl<T> JsonSchema for ReadCell<T>
+where
+    T: JsonSchema,
+{
+    #[inline]
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    #[inline]
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    #[inline]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::json_schema(generator)
+    }
+}
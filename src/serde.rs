@@ -0,0 +1,69 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ReadCell;
+
+/// Serializes `ReadCell<T>` exactly as `T` would be serialized.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+///
+/// let c = ReadCell::new([1u32, 2, 3]);
+///
+/// assert_eq!(serde_json::to_string(&c).unwrap(), "[1,2,3]");
+/// ```
+impl<T> Serialize for ReadCell<T>
+where
+    T: Serialize + Copy,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+/// Deserializes `ReadCell<T>` from the same representation as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::ReadCell;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     volume: ReadCell<f32>,
+///     mute: ReadCell<bool>,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(transparent)]
+/// struct Id(ReadCell<u64>);
+///
+/// let json = r#"{"volume":0.5,"mute":true}"#;
+/// let config: Config = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(config.volume.get(), 0.5);
+/// assert!(config.mute.get());
+/// assert_eq!(serde_json::to_string(&config).unwrap(), json);
+///
+/// let id: Id = serde_json::from_str("42").unwrap();
+/// assert_eq!(id.0.get(), 42);
+/// assert_eq!(serde_json::to_string(&id).unwrap(), serde_json::to_string(&42u64).unwrap());
+/// ```
+impl<'de, T> Deserialize<'de> for ReadCell<T>
+where
+    T: Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(ReadCell::new)
+    }
+}
@@ -0,0 +1,501 @@
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::Ordering,
+};
+
+use crate::ReadCell;
+
+/// A thread-safe read-only view of an atomically updated value.
+///
+/// `SyncReadCell<T>` is to atomics what [`ReadCell<T>`] is to [`Cell<T>`].
+/// It has the same memory layout as the atomic type for `T`,
+/// e.g. `SyncReadCell<u32>` has layout of [`AtomicU32`],
+/// and a `&SyncReadCell<T>` can be created from a reference to it with [`from_atomic`].
+/// The value can't be modified through `&SyncReadCell<T>`,
+/// but it may be modified through the aliasing atomic from any thread.
+///
+/// Unlike [`ReadCell`], `SyncReadCell` is `Sync`, since every read is an atomic load,
+/// which never tears.
+///
+/// [`ReadCell<T>`]: `crate::ReadCell`
+/// [`ReadCell`]: `crate::ReadCell`
+/// [`Cell<T>`]: `core::cell::Cell`
+/// [`AtomicU32`]: `core::sync::atomic::AtomicU32`
+/// [`from_atomic`]: `SyncReadCell::from_atomic`
+///
+/// # Examples
+///
+/// ```
+/// use std::{sync::atomic::{AtomicU32, Ordering}, thread};
+/// use read_cell::SyncReadCell;
+///
+/// let progress = AtomicU32::new(0);
+/// let view = SyncReadCell::from_atomic(&progress);
+///
+/// thread::scope(|s| {
+///     s.spawn(|| {
+///         for i in 1..=100 {
+///             progress.store(i, Ordering::Release);
+///         }
+///     });
+///
+///     for _ in 0..2 {
+///         s.spawn(|| {
+///             let mut last = 0;
+///             while last < 100 {
+///                 let current = view.get();
+///                 assert!(current >= last);
+///                 last = current;
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(view.get(), 100);
+/// ```
+#[repr(transparent)]
+pub struct SyncReadCell<T: AtomicValue> {
+    atomic: T::Atomic,
+}
+
+/// A value that can be stored in an atomic and read through a [`SyncReadCell`].
+///
+/// Implemented for primitive integers, `bool` and raw pointers
+/// on targets that support atomics of the matching width.
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait AtomicValue: Copy + sealed::Sealed {
+    /// The atomic type storing the value, e.g. [`AtomicU32`] for `u32`.
+    ///
+    /// [`AtomicU32`]: `core::sync::atomic::AtomicU32`
+    type Atomic;
+
+    #[doc(hidden)]
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    #[doc(hidden)]
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    #[doc(hidden)]
+    fn into_value(atomic: Self::Atomic) -> Self;
+}
+
+/// An atomic type that can be viewed as a [`SyncReadCell`].
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait AtomicType: sealed::Sealed {
+    /// The type of the value stored in the atomic, e.g. `u32` for [`AtomicU32`].
+    ///
+    /// [`AtomicU32`]: `core::sync::atomic::AtomicU32`
+    type Value: AtomicValue<Atomic = Self>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_atomic_value {
+    ($($width:literal => $($ty:ty => $atomic:ident),*;)*) => {$($(
+        #[cfg(target_has_atomic = $width)]
+        impl sealed::Sealed for $ty {}
+
+        #[cfg(target_has_atomic = $width)]
+        impl sealed::Sealed for core::sync::atomic::$atomic {}
+
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicType for core::sync::atomic::$atomic {
+            type Value = $ty;
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicValue for $ty {
+            type Atomic = core::sync::atomic::$atomic;
+
+            #[inline]
+            fn new_atomic(value: $ty) -> core::sync::atomic::$atomic {
+                core::sync::atomic::$atomic::new(value)
+            }
+
+            #[inline]
+            fn load(atomic: &core::sync::atomic::$atomic, order: Ordering) -> $ty {
+                atomic.load(order)
+            }
+
+            #[inline]
+            fn into_value(atomic: core::sync::atomic::$atomic) -> $ty {
+                atomic.into_inner()
+            }
+        }
+    )*)*};
+}
+
+impl_atomic_value! {
+    "8" => bool => AtomicBool, u8 => AtomicU8, i8 => AtomicI8;
+    "16" => u16 => AtomicU16, i16 => AtomicI16;
+    "32" => u32 => AtomicU32, i32 => AtomicI32;
+    "64" => u64 => AtomicU64, i64 => AtomicI64;
+    "ptr" => usize => AtomicUsize, isize => AtomicIsize;
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> sealed::Sealed for *mut T {}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> sealed::Sealed for core::sync::atomic::AtomicPtr<T> {}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> AtomicType for core::sync::atomic::AtomicPtr<T> {
+    type Value = *mut T;
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> AtomicValue for *mut T {
+    type Atomic = core::sync::atomic::AtomicPtr<T>;
+
+    #[inline]
+    fn new_atomic(value: *mut T) -> core::sync::atomic::AtomicPtr<T> {
+        core::sync::atomic::AtomicPtr::new(value)
+    }
+
+    #[inline]
+    fn load(atomic: &core::sync::atomic::AtomicPtr<T>, order: Ordering) -> *mut T {
+        atomic.load(order)
+    }
+
+    #[inline]
+    fn into_value(atomic: core::sync::atomic::AtomicPtr<T>) -> *mut T {
+        atomic.into_inner()
+    }
+}
+
+impl<T: AtomicValue> SyncReadCell<T> {
+    /// Creates a new `SyncReadCell` containing the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::SyncReadCell;
+    ///
+    /// let c = SyncReadCell::new(5u32);
+    /// assert_eq!(c.get(), 5);
+    /// ```
+    #[inline]
+    pub fn new(value: T) -> SyncReadCell<T> {
+        SyncReadCell {
+            atomic: T::new_atomic(value),
+        }
+    }
+
+    /// Unwraps the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use read_cell::SyncReadCell;
+    ///
+    /// assert!(SyncReadCell::new(true).into_inner());
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        T::into_value(self.atomic)
+    }
+
+    /// Returns a `&SyncReadCell<T>` from a reference to the atomic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicI64, Ordering};
+    /// use read_cell::SyncReadCell;
+    ///
+    /// let balance = AtomicI64::new(-5);
+    /// let view: &SyncReadCell<i64> = SyncReadCell::from_atomic(&balance);
+    ///
+    /// balance.fetch_add(10, Ordering::Relaxed);
+    /// assert_eq!(view.get(), 5);
+    /// ```
+    #[inline]
+    pub const fn from_atomic<A>(atomic: &A) -> &SyncReadCell<T>
+    where
+        A: AtomicType<Value = T>,
+        T: AtomicValue<Atomic = A>,
+    {
+        // SAFETY: `SyncReadCell<T>` is `#[repr(transparent)]` over the atomic
+        // and disallows mutations.
+        unsafe { &*(atomic as *const A as *const SyncReadCell<T>) }
+    }
+
+    /// Loads the value with the given memory ordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`] or [`AcqRel`], same as atomic loads do.
+    ///
+    /// [`Release`]: `Ordering::Release`
+    /// [`AcqRel`]: `Ordering::AcqRel`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use read_cell::SyncReadCell;
+    ///
+    /// let len = AtomicUsize::new(3);
+    /// let view = SyncReadCell::from_atomic(&len);
+    ///
+    /// assert_eq!(view.load(Ordering::Relaxed), 3);
+    /// ```
+    #[inline]
+    pub fn load(&self, order: Ordering) -> T {
+        T::load(&self.atomic, order)
+    }
+
+    /// Loads the value with [`Acquire`] ordering.
+    ///
+    /// This synchronizes with [`Release`] stores to the aliasing atomic,
+    /// so writes made before such a store are visible after reading the stored value.
+    ///
+    /// [`Acquire`]: `Ordering::Acquire`
+    /// [`Release`]: `Ordering::Release`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{ptr, sync::atomic::{AtomicPtr, Ordering}};
+    /// use read_cell::SyncReadCell;
+    ///
+    /// let mut value = 5;
+    /// let slot = AtomicPtr::new(ptr::null_mut());
+    /// let view: &SyncReadCell<*mut i32> = SyncReadCell::from_atomic(&slot);
+    ///
+    /// assert!(view.get().is_null());
+    ///
+    /// slot.store(&mut value, Ordering::Release);
+    /// assert_eq!(unsafe { *view.get() }, 5);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> T {
+        self.load(Ordering::Acquire)
+    }
+}
+
+impl<T: AtomicValue> ReadCell<T> {
+    /// Returns a `&ReadCell<T>` from a reference to the atomic storing `T`.
+    ///
+    /// Reads through the view are plain, non-atomic reads.
+    /// They never tear, since all modifications of the atomic happen on the current thread,
+    /// but they do not synchronize with anything either.
+    /// Use [`SyncReadCell::from_atomic`] to read the atomic from multiple threads.
+    ///
+    /// # Safety
+    ///
+    /// The atomic must not be modified from other threads while the returned reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use read_cell::ReadCell;
+    ///
+    /// let counter = AtomicU32::new(1);
+    ///
+    /// // SAFETY: `counter` is not shared with other threads.
+    /// let view: &ReadCell<u32> = unsafe { ReadCell::from_atomic(&counter) };
+    /// assert_eq!(view.get(), 1);
+    ///
+    /// counter.store(2, Ordering::Relaxed);
+    /// assert_eq!(view.get(), 2);
+    ///
+    /// counter.fetch_add(3, Ordering::Relaxed);
+    /// assert_eq!(view.get(), 5);
+    /// ```
+    ///
+    /// Works for every atomic type, including `AtomicBool` and `AtomicPtr`.
+    ///
+    /// ```
+    /// use std::{ptr, sync::atomic::{AtomicBool, AtomicI8, AtomicPtr, Ordering}};
+    /// use read_cell::ReadCell;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// let level = AtomicI8::new(0);
+    /// let slot = AtomicPtr::new(ptr::null_mut());
+    ///
+    /// // SAFETY: Atomics are not shared with other threads.
+    /// let (flag_view, level_view, slot_view) = unsafe {
+    ///     (
+    ///         ReadCell::from_atomic(&flag),
+    ///         ReadCell::from_atomic(&level),
+    ///         ReadCell::from_atomic(&slot),
+    ///     )
+    /// };
+    ///
+    /// let mut value = 7u64;
+    /// flag.store(true, Ordering::Relaxed);
+    /// level.store(-3, Ordering::Relaxed);
+    /// slot.store(&mut value, Ordering::Relaxed);
+    ///
+    /// assert!(flag_view.get());
+    /// assert_eq!(level_view.get(), -3);
+    /// assert_eq!(slot_view.get(), &mut value as *mut u64);
+    /// ```
+    #[inline]
+    pub const unsafe fn from_atomic<A>(atomic: &A) -> &ReadCell<T>
+    where
+        A: AtomicType<Value = T>,
+        T: AtomicValue<Atomic = A>,
+    {
+        // SAFETY: Atomic types have the same size as `T` and at least its alignment.
+        // Caller guarantees that the atomic is not modified from other threads.
+        unsafe { ReadCell::from_ptr(atomic as *const A as *const T) }
+    }
+}
+
+impl<T: AtomicValue + Default> Default for SyncReadCell<T> {
+    /// Creates a `SyncReadCell<T>`, with the `Default` value for T.
+    #[inline]
+    fn default() -> SyncReadCell<T> {
+        SyncReadCell::new(T::default())
+    }
+}
+
+impl<T: AtomicValue> From<T> for SyncReadCell<T> {
+    /// Creates a new `SyncReadCell<T>` containing the given value.
+    #[inline]
+    fn from(value: T) -> SyncReadCell<T> {
+        SyncReadCell::new(value)
+    }
+}
+
+/// Formats the loaded value.
+///
+/// # Examples
+///
+/// ```
+/// use read_cell::SyncReadCell;
+///
+/// assert_eq!(format!("{:?}", SyncReadCell::new(5u8)), "SyncReadCell { value: 5 }");
+/// ```
+impl<T: AtomicValue + Debug> Debug for SyncReadCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncReadCell")
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        ptr,
+        sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicUsize, Ordering},
+    };
+    use std::{boxed::Box, thread};
+
+    use super::SyncReadCell;
+
+    const UPDATES: u32 = if cfg!(miri) { 20 } else { 10_000 };
+
+    #[test]
+    fn readers_observe_monotonic_updates() {
+        let progress = AtomicU32::new(0);
+        let view = SyncReadCell::from_atomic(&progress);
+
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    let mut last = 0;
+                    while last < UPDATES {
+                        let current = view.get();
+                        assert!(current >= last);
+                        last = current;
+                    }
+                });
+            }
+
+            s.spawn(|| {
+                for i in 1..=UPDATES {
+                    progress.store(i, Ordering::Release);
+                }
+            });
+        });
+
+        assert_eq!(view.get(), UPDATES);
+    }
+
+    #[test]
+    fn concurrent_writers() {
+        let counter = AtomicUsize::new(0);
+        let view = SyncReadCell::from_atomic(&counter);
+
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..UPDATES {
+                        counter.fetch_add(1, Ordering::Release);
+                    }
+                });
+            }
+
+            s.spawn(|| {
+                let mut last = 0;
+                while last < 4 * UPDATES as usize {
+                    let current = view.get();
+                    assert!(current >= last);
+                    last = current;
+                }
+            });
+        });
+
+        assert_eq!(view.get(), 4 * UPDATES as usize);
+    }
+
+    #[test]
+    fn acquire_get_synchronizes_with_release_store() {
+        let payload = AtomicU32::new(0);
+        let ready = AtomicBool::new(false);
+        let view = SyncReadCell::from_atomic(&ready);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                while !view.get() {
+                    thread::yield_now();
+                }
+                assert_eq!(payload.load(Ordering::Relaxed), 42);
+            });
+
+            s.spawn(|| {
+                payload.store(42, Ordering::Relaxed);
+                ready.store(true, Ordering::Release);
+            });
+        });
+    }
+
+    #[test]
+    fn published_pointer_is_readable() {
+        let slot = AtomicPtr::new(ptr::null_mut::<[u64; 4]>());
+        let view = SyncReadCell::from_atomic(&slot);
+
+        thread::scope(|s| {
+            for _ in 0..2 {
+                s.spawn(|| loop {
+                    let ptr = view.get();
+                    if !ptr.is_null() {
+                        // SAFETY: Pointer is published with `Release` store
+                        // and freed only after all readers are joined.
+                        assert_eq!(unsafe { *ptr }, [1, 2, 3, 4]);
+                        break;
+                    }
+                    thread::yield_now();
+                });
+            }
+
+            s.spawn(|| {
+                slot.store(Box::into_raw(Box::new([1, 2, 3, 4])), Ordering::Release);
+            });
+        });
+
+        // SAFETY: Pointer was created by `Box::into_raw` and all readers are joined.
+        drop(unsafe { Box::from_raw(view.get()) });
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use crate::ReadCell;
+
+impl<T: Copy> ReadCell<[T]> {
+    /// Copies all elements into a new `Vec`.
+    ///
+    /// Elements are read one by one at the time of the call,
+    /// so the snapshot reflects all writes made through an aliasing [`Cell`] before it.
+    ///
+    /// Available with `alloc` feature.
+    ///
+    /// [`Cell`]: `core::cell::Cell`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use read_cell::ReadCell;
+    ///
+    /// let buffer: &Cell<[u32]> = &Cell::new([1, 2, 3]);
+    /// let shared: &ReadCell<[u32]> = ReadCell::from_cell(buffer);
+    ///
+    /// let before = shared.to_vec();
+    /// buffer.as_slice_of_cells()[0].set(4);
+    /// let after = shared.to_vec();
+    ///
+    /// assert_eq!(before, [1, 2, 3]);
+    /// assert_eq!(after, [4, 2, 3]);
+    /// assert!(shared[3..].to_vec().is_empty());
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_slice_of_cells().iter().map(ReadCell::get).collect()
+    }
+}
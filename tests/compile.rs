@@ -0,0 +1,8 @@
+#[test]
+fn compile() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/send.rs");
+    t.compile_fail("tests/ui/not_sync.rs");
+    t.compile_fail("tests/ui/not_send.rs");
+    t.compile_fail("tests/ui/shared_across_threads.rs");
+}
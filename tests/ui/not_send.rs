@@ -0,0 +1,9 @@
+use std::rc::Rc;
+
+use read_cell::ReadCell;
+
+fn assert_send<T: Send>() {}
+
+fn main() {
+    assert_send::<ReadCell<Rc<u32>>>();
+}
@@ -0,0 +1,8 @@
+use read_cell::ReadCell;
+
+fn assert_sync<T: Sync + ?Sized>() {}
+
+fn main() {
+    assert_sync::<ReadCell<u32>>();
+    assert_sync::<ReadCell<[u8]>>();
+}
@@ -0,0 +1,15 @@
+use std::thread;
+
+use read_cell::ReadCell;
+
+fn assert_send<T: Send + ?Sized>() {}
+
+fn main() {
+    assert_send::<ReadCell<u32>>();
+    assert_send::<ReadCell<[u8]>>();
+    assert_send::<ReadCell<str>>();
+
+    let c = ReadCell::new(5);
+    let value = thread::spawn(move || c.get()).join().unwrap();
+    assert_eq!(value, 5);
+}
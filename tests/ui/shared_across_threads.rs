@@ -0,0 +1,13 @@
+use std::{cell::Cell, thread};
+
+use read_cell::ReadCell;
+
+fn main() {
+    let cell = Cell::new(5);
+    let view = ReadCell::from_cell(&cell);
+
+    thread::scope(|s| {
+        s.spawn(|| view.get());
+        cell.set(6);
+    });
+}